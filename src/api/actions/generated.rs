@@ -0,0 +1,26 @@
+//! Generated by `cargo xtask codegen` from GitHub's OpenAPI description.
+//!
+//! Don't edit it by hand, allowlist the operation in `xtask/spec/allowlist`.
+
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "actions",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/actions/artifacts/{}/{}",
+	docs = "https://docs.github.com/en/rest/actions/artifacts#download-an-artifact",
+	response = "binary"
+)]
+pub struct DownloadAnArtifact<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub artifact_id: u64,
+	#[path_param]
+	pub archive_format: &'a str,
+}
@@ -0,0 +1,9 @@
+//! Actions related methods.
+//!
+//! GitHub reference(s):
+//! - <https://docs.github.com/en/rest/actions>
+
+#[cfg(test)] mod test;
+
+mod generated;
+pub use generated::*;
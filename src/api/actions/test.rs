@@ -0,0 +1,13 @@
+use super::*;
+use crate::prelude::*;
+
+#[test]
+fn download_an_artifact_should_work() {
+	let request = download_an_artifact("hack-ink", "githuber", 4_294_967_296, "zip");
+
+	assert_eq!(
+		request.api(),
+		"https://api.github.com/repos/hack-ink/githuber/actions/artifacts/4294967296/zip"
+	);
+	const { assert!(DownloadAnArtifact::METADATA.binary_response) };
+}
@@ -0,0 +1,877 @@
+//! Branches related methods.
+//!
+//! GitHub reference(s):
+//! - <https://docs.github.com/en/rest/branches/branches>
+//! - <https://docs.github.com/en/rest/branches/branch-protection>
+
+#[cfg(test)] mod test;
+
+// std
+use std::borrow::Cow;
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches"
+)]
+pub struct ListBranches<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	pub protected: Option<bool>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}"
+)]
+pub struct GetABranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-branch-protection"
+)]
+pub struct GetBranchProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+/// The four essential parameters are sent as `null` to disable the protection.
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#update-branch-protection"
+)]
+pub struct UpdateBranchProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	pub required_status_checks: Option<RequiredStatusChecks<'a>>,
+	#[payload_ess_param]
+	pub enforce_admins: Option<bool>,
+	#[payload_ess_param]
+	#[validate]
+	pub required_pull_request_reviews: Option<RequiredPullRequestReviews<'a>>,
+	#[payload_ess_param]
+	pub restrictions: Option<PushRestrictions<'a>>,
+	pub required_linear_history: Option<bool>,
+	pub allow_force_pushes: Option<bool>,
+	pub allow_deletions: Option<bool>,
+	pub block_creations: Option<bool>,
+	pub required_conversation_resolution: Option<bool>,
+	pub lock_branch: Option<bool>,
+	pub allow_fork_syncing: Option<bool>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#delete-branch-protection"
+)]
+pub struct DeleteBranchProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/enforce_admins",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-admin-branch-protection"
+)]
+pub struct GetAdminBranchProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/enforce_admins",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#set-admin-branch-protection"
+)]
+pub struct SetAdminBranchProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/enforce_admins",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#delete-admin-branch-protection"
+)]
+pub struct DeleteAdminBranchProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-pull-request-review-protection"
+)]
+pub struct GetPullRequestReviewProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#update-pull-request-review-protection"
+)]
+pub struct UpdatePullRequestReviewProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	pub dismissal_restrictions: Option<ReviewActors<'a>>,
+	pub dismiss_stale_reviews: Option<bool>,
+	pub require_code_owner_reviews: Option<bool>,
+	#[range(0..=6)]
+	pub required_approving_review_count: Option<u8>,
+	pub require_last_push_approval: Option<bool>,
+	pub bypass_pull_request_allowances: Option<ReviewActors<'a>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#delete-pull-request-review-protection"
+)]
+pub struct DeletePullRequestReviewProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_signatures",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-commit-signature-protection"
+)]
+pub struct GetCommitSignatureProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_signatures",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#create-commit-signature-protection"
+)]
+pub struct CreateCommitSignatureProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_signatures",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#delete-commit-signature-protection"
+)]
+pub struct DeleteCommitSignatureProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_status_checks",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-status-checks-protection"
+)]
+pub struct GetStatusChecksProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_status_checks",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#update-status-check-protection"
+)]
+pub struct UpdateStatusCheckProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	pub strict: Option<bool>,
+	#[into]
+	pub contexts: Option<List<'a>>,
+	pub checks: Option<Vec<StatusCheck<'a>>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/required_status_checks",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#remove-status-check-protection"
+)]
+pub struct RemoveStatusCheckProtection<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-access-restrictions"
+)]
+pub struct GetAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#delete-access-restrictions"
+)]
+pub struct DeleteAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-apps-with-access-to-the-protected-branch"
+)]
+pub struct GetAppsWithAccessToTheProtectedBranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#add-app-access-restrictions"
+)]
+pub struct AddAppAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub apps: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#set-app-access-restrictions"
+)]
+pub struct SetAppAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub apps: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#remove-app-access-restrictions"
+)]
+pub struct RemoveAppAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub apps: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-teams-with-access-to-the-protected-branch"
+)]
+pub struct GetTeamsWithAccessToTheProtectedBranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#add-team-access-restrictions"
+)]
+pub struct AddTeamAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub teams: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#set-team-access-restrictions"
+)]
+pub struct SetTeamAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub teams: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#remove-team-access-restrictions"
+)]
+pub struct RemoveTeamAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub teams: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/users",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#get-users-with-access-to-the-protected-branch"
+)]
+pub struct GetUsersWithAccessToTheProtectedBranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/users",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#add-user-access-restrictions"
+)]
+pub struct AddUserAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub users: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/users",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#set-user-access-restrictions"
+)]
+pub struct SetUserAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub users: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/protection/restrictions/users",
+	docs = "https://docs.github.com/en/rest/branches/branch-protection#remove-user-access-restrictions"
+)]
+pub struct RemoveUserAccessRestrictions<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub users: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/branches/{}/rename"
+)]
+pub struct RenameABranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub branch: &'a str,
+	#[payload_ess_param]
+	pub new_name: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/merge-upstream"
+)]
+pub struct SyncAForkBranchWithTheUpstreamRepository<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub branch: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "branches",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/merges"
+)]
+pub struct MergeABranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub base: &'a str,
+	#[payload_ess_param]
+	pub head: &'a str,
+	pub commit_message: Option<&'a str>,
+}
+
+/// `required_status_checks` of [`UpdateBranchProtection`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequiredStatusChecks<'a> {
+	/// Require the branches to be up to date before merging.
+	pub strict: bool,
+	/// Deprecated by GitHub in favor of [`checks`](Self::checks).
+	pub contexts: List<'a>,
+	#[allow(missing_docs)]
+	pub checks: Option<Vec<StatusCheck<'a>>>,
+}
+impl ToParam for RequiredStatusChecks<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([
+			("strict", self.strict.to_param()),
+			("contexts", self.contexts.to_param()),
+			("checks", self.checks.to_param()),
+		])
+	}
+}
+impl IntoStatic for RequiredStatusChecks<'_> {
+	type Static = RequiredStatusChecks<'static>;
+
+	fn into_static(self) -> Self::Static {
+		RequiredStatusChecks {
+			strict: self.strict,
+			contexts: self.contexts.into_static(),
+			checks: self.checks.into_static(),
+		}
+	}
+}
+
+/// A required status check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusCheck<'a> {
+	/// Name of the check.
+	pub context: Cow<'a, str>,
+	/// The app which must set the status.
+	///
+	/// GitHub picks the app which set the status most recently if it's `None`.
+	pub app_id: Option<StatusCheckApp>,
+}
+impl ToParam for StatusCheck<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([("context", self.context.to_param()), ("app_id", self.app_id.to_param())])
+	}
+}
+impl IntoStatic for StatusCheck<'_> {
+	type Static = StatusCheck<'static>;
+
+	fn into_static(self) -> Self::Static {
+		StatusCheck { context: self.context.into_static(), app_id: self.app_id }
+	}
+}
+
+/// The app which must set a required status check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusCheckApp {
+	/// Any app, sent as `-1`.
+	Any,
+	/// The app of the ID.
+	Id(u64),
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for StatusCheckApp {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self {
+			Self::Any => serializer.serialize_i64(-1),
+			Self::Id(id) => serializer.serialize_u64(*id),
+		}
+	}
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StatusCheckApp {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(untagged)]
+		enum Repr {
+			Id(u64),
+			Any(i64),
+		}
+
+		match Repr::deserialize(deserializer)? {
+			Repr::Id(id) => Ok(Self::Id(id)),
+			Repr::Any(-1) => Ok(Self::Any),
+			Repr::Any(id) => Err(serde::de::Error::custom(format!(
+				"expect an app ID or `-1` for any app but found {id}"
+			))),
+		}
+	}
+}
+impl ToParam for StatusCheckApp {
+	fn to_param(&self) -> Param {
+		match self {
+			Self::Any => Param::Integer(-1),
+			Self::Id(id) => id.to_param(),
+		}
+	}
+}
+
+/// `required_pull_request_reviews` of [`UpdateBranchProtection`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequiredPullRequestReviews<'a> {
+	/// Who can dismiss the reviews.
+	pub dismissal_restrictions: Option<ReviewActors<'a>>,
+	#[allow(missing_docs)]
+	pub dismiss_stale_reviews: Option<bool>,
+	#[allow(missing_docs)]
+	pub require_code_owner_reviews: Option<bool>,
+	/// From `0` to `6`.
+	pub required_approving_review_count: Option<u8>,
+	#[allow(missing_docs)]
+	pub require_last_push_approval: Option<bool>,
+	/// Who can bypass the required reviews.
+	pub bypass_pull_request_allowances: Option<ReviewActors<'a>>,
+}
+impl ToParam for RequiredPullRequestReviews<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([
+			("dismissal_restrictions", self.dismissal_restrictions.to_param()),
+			("dismiss_stale_reviews", self.dismiss_stale_reviews.to_param()),
+			("require_code_owner_reviews", self.require_code_owner_reviews.to_param()),
+			("required_approving_review_count", self.required_approving_review_count.to_param()),
+			("require_last_push_approval", self.require_last_push_approval.to_param()),
+			("bypass_pull_request_allowances", self.bypass_pull_request_allowances.to_param()),
+		])
+	}
+}
+impl IntoStatic for RequiredPullRequestReviews<'_> {
+	type Static = RequiredPullRequestReviews<'static>;
+
+	fn into_static(self) -> Self::Static {
+		RequiredPullRequestReviews {
+			dismissal_restrictions: self.dismissal_restrictions.into_static(),
+			dismiss_stale_reviews: self.dismiss_stale_reviews,
+			require_code_owner_reviews: self.require_code_owner_reviews,
+			required_approving_review_count: self.required_approving_review_count,
+			require_last_push_approval: self.require_last_push_approval,
+			bypass_pull_request_allowances: self.bypass_pull_request_allowances.into_static(),
+		}
+	}
+}
+impl Validate for RequiredPullRequestReviews<'_> {
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(value) = self.required_approving_review_count {
+			if !(0..=6).contains(&value) {
+				return Err(Error::OutOfRange {
+					param: "required_pull_request_reviews.required_approving_review_count",
+					value: value.to_string(),
+					range: "0..=6",
+				});
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Users, teams and apps of the review protection.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReviewActors<'a> {
+	/// Logins of the users.
+	pub users: Option<List<'a>>,
+	/// Slugs of the teams.
+	pub teams: Option<List<'a>>,
+	/// Slugs of the apps.
+	pub apps: Option<List<'a>>,
+}
+impl ToParam for ReviewActors<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([
+			("users", self.users.to_param()),
+			("teams", self.teams.to_param()),
+			("apps", self.apps.to_param()),
+		])
+	}
+}
+impl IntoStatic for ReviewActors<'_> {
+	type Static = ReviewActors<'static>;
+
+	fn into_static(self) -> Self::Static {
+		ReviewActors {
+			users: self.users.into_static(),
+			teams: self.teams.into_static(),
+			apps: self.apps.into_static(),
+		}
+	}
+}
+
+/// `restrictions` of [`UpdateBranchProtection`], who can push to the branch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PushRestrictions<'a> {
+	/// Logins of the users.
+	pub users: List<'a>,
+	/// Slugs of the teams.
+	pub teams: List<'a>,
+	/// Slugs of the apps.
+	pub apps: Option<List<'a>>,
+}
+impl ToParam for PushRestrictions<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([
+			("users", self.users.to_param()),
+			("teams", self.teams.to_param()),
+			("apps", self.apps.to_param()),
+		])
+	}
+}
+impl IntoStatic for PushRestrictions<'_> {
+	type Static = PushRestrictions<'static>;
+
+	fn into_static(self) -> Self::Static {
+		PushRestrictions {
+			users: self.users.into_static(),
+			teams: self.teams.into_static(),
+			apps: self.apps.into_static(),
+		}
+	}
+}
@@ -0,0 +1,113 @@
+use super::*;
+
+#[test]
+fn update_branch_protection_should_work() {
+	let request = update_branch_protection(
+		"hack-ink",
+		"githuber",
+		"main",
+		Some(RequiredStatusChecks {
+			strict: true,
+			checks: Some(vec![
+				StatusCheck { context: "ci".into(), app_id: None },
+				StatusCheck { context: "lint".into(), app_id: Some(StatusCheckApp::Any) },
+			]),
+			..Default::default()
+		}),
+		None,
+		None,
+		Some(PushRestrictions { users: ["aurexav"].into(), ..Default::default() }),
+	);
+
+	assert_eq!(
+		request.api(),
+		"https://api.github.com/repos/hack-ink/githuber/branches/main/protection"
+	);
+	assert_eq!(
+		request.payload_params(),
+		vec![
+			(
+				"required_status_checks",
+				Param::object([
+					("strict", Param::Bool(true)),
+					("contexts", Param::Array(Vec::new())),
+					(
+						"checks",
+						Param::Array(vec![
+							Param::object([("context", Param::String("ci".into()))]),
+							Param::object([
+								("context", Param::String("lint".into())),
+								("app_id", Param::Integer(-1))
+							]),
+						])
+					),
+				])
+			),
+			("enforce_admins", Param::Null),
+			("required_pull_request_reviews", Param::Null),
+			(
+				"restrictions",
+				Param::object([
+					("users", Param::Array(vec![Param::String("aurexav".into())])),
+					("teams", Param::Array(Vec::new())),
+				])
+			),
+		]
+	);
+}
+
+#[test]
+fn update_pull_request_review_protection_should_validate() {
+	assert!(matches!(
+		update_pull_request_review_protection("hack-ink", "githuber", "main")
+			.required_approving_review_count(7)
+			.validate(),
+		Err(Error::OutOfRange { param: "required_approving_review_count", .. })
+	));
+	assert!(matches!(
+		update_branch_protection(
+			"hack-ink",
+			"githuber",
+			"main",
+			None,
+			None,
+			Some(RequiredPullRequestReviews {
+				required_approving_review_count: Some(7),
+				..Default::default()
+			}),
+			None,
+		)
+		.validate(),
+		Err(Error::OutOfRange {
+			param: "required_pull_request_reviews.required_approving_review_count",
+			..
+		})
+	));
+}
+
+#[test]
+fn access_restrictions_should_work() {
+	let request = remove_user_access_restrictions("hack-ink", "githuber", "main", ["aurexav"]);
+
+	assert_eq!(
+		request.api(),
+		"https://api.github.com/repos/hack-ink/githuber/branches/main/protection/restrictions/users"
+	);
+	assert_eq!(
+		request.payload_params(),
+		vec![("users", Param::Array(vec![Param::String("aurexav".into())]))]
+	);
+	assert_eq!(RemoveUserAccessRestrictions::METHOD, Method::Delete);
+}
+
+#[test]
+fn branch_with_slashes_should_work() {
+	assert_eq!(
+		get_a_branch("hack-ink", "githuber", "feature/x y").api(),
+		"https://api.github.com/repos/hack-ink/githuber/branches/feature/x%20y"
+	);
+	assert_eq!(
+		get_branch_protection("hack-ink", "githuber", "release/v1").api(),
+		"https://api.github.com/repos/hack-ink/githuber/branches/release/v1/protection"
+	);
+}
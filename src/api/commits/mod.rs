@@ -23,8 +23,12 @@ pub struct ListCommits<'a> {
 	pub sha: Option<&'a str>,
 	pub path: Option<&'a str>,
 	pub author: Option<&'a str>,
-	pub since: Option<&'a str>,
-	pub until: Option<&'a str>,
+	pub committer: Option<&'a str>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[into]
+	pub until: Option<Timestamp>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -41,7 +45,7 @@ pub struct ListBranchesForHeadCommit<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
-	#[path_param]
+	#[path_param(multi_segment)]
 	pub commit_sha: &'a str,
 }
 
@@ -57,8 +61,9 @@ pub struct ListPullRequestsAssociatedWithACommit<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
-	#[path_param]
+	#[path_param(multi_segment)]
 	pub commit_sha: &'a str,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -75,8 +80,9 @@ pub struct GetACommit<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
-	#[path_param]
+	#[path_param(multi_segment)]
 	pub r#ref: &'a str,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -93,8 +99,9 @@ pub struct CompareTwoCommits<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
-	#[path_param]
+	#[path_param(multi_segment)]
 	pub basehead: &'a str,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -40,3 +40,78 @@ async fn compare_two_commits_should_work() {
 
 	api_client().unwrap().get(request).await.unwrap();
 }
+
+#[test]
+fn into_owned_should_work() {
+	let request = {
+		let basehead = String::from("main...archived");
+
+		compare_two_commits("hack-ink", "githuber", basehead.as_str()).per_page(10).into_owned()
+	};
+	let request = std::thread::spawn(move || request).join().unwrap();
+
+	assert_eq!(
+		request.api(),
+		"https://api.github.com/repos/hack-ink/githuber/compare/main...archived"
+	);
+	assert_eq!(request.per_page, Some(10));
+}
+
+#[test]
+fn timestamp_should_work() {
+	assert_eq!(
+		list_commits("hack-ink", "githuber").since("2024-01-01T00:00:00Z").payload_params(),
+		vec![("since", Param::String("2024-01-01T00:00:00Z".into()))]
+	);
+
+	#[cfg(feature = "time")]
+	{
+		let date_time = time::OffsetDateTime::from_unix_timestamp(1_704_067_200)
+			.unwrap()
+			.to_offset(time::UtcOffset::from_hms(8, 0, 0).unwrap());
+		let timestamp = Timestamp::try_from(date_time).unwrap();
+
+		assert_eq!(timestamp.as_str(), "2024-01-01T00:00:00Z");
+		assert_eq!(time::OffsetDateTime::try_from(&timestamp).unwrap(), date_time);
+	}
+	#[cfg(feature = "chrono")]
+	{
+		let date_time = chrono::DateTime::from_timestamp(1_704_067_200, 0)
+			.unwrap()
+			.with_timezone(&chrono::FixedOffset::east_opt(8 * 3600).unwrap());
+		let timestamp = Timestamp::from(date_time);
+
+		assert_eq!(timestamp.as_str(), "2024-01-01T00:00:00Z");
+		assert_eq!(chrono::DateTime::<chrono::Utc>::try_from(&timestamp).unwrap(), date_time);
+	}
+}
+
+#[cfg(all(feature = "serde", feature = "time", feature = "chrono"))]
+#[test]
+fn serde_date_time_should_work() {
+	#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+	struct Model {
+		#[serde(with = "time_date_time")]
+		created_at: time::OffsetDateTime,
+		#[serde(default, with = "chrono_date_time::option")]
+		updated_at: Option<chrono::DateTime<chrono::Utc>>,
+	}
+
+	let json = serde_json::json!({
+		"created_at": "2024-01-01T00:00:00Z",
+		"updated_at": "2024-01-02T00:00:00Z"
+	});
+	let model = serde_json::from_value::<Model>(json.clone()).unwrap();
+
+	assert_eq!(model.created_at.unix_timestamp(), 1_704_067_200);
+	assert_eq!(model.updated_at.unwrap().timestamp(), 1_704_153_600);
+	assert_eq!(serde_json::to_value(&model).unwrap(), json);
+	assert_eq!(
+		serde_json::from_value::<Model>(
+			serde_json::json!({ "created_at": "2024-01-01T00:00:00Z" })
+		)
+		.unwrap()
+		.updated_at,
+		None
+	);
+}
@@ -0,0 +1,178 @@
+//! Generated by `cargo xtask codegen` from the `#[api_impl::api]` structs.
+//!
+//! Don't edit it by hand, it's regenerated along with the APIs.
+
+// hack-ink
+use super::*;
+use crate::api::{
+	actions::*, branches::*, commits::*, issues::*, pulls::*, reactions::*, releases::*, repos::*,
+};
+
+register_endpoints! {
+	DownloadAnArtifact<'a>,
+	ListBranches<'a>,
+	GetABranch<'a>,
+	GetBranchProtection<'a>,
+	UpdateBranchProtection<'a>,
+	DeleteBranchProtection<'a>,
+	GetAdminBranchProtection<'a>,
+	SetAdminBranchProtection<'a>,
+	DeleteAdminBranchProtection<'a>,
+	GetPullRequestReviewProtection<'a>,
+	UpdatePullRequestReviewProtection<'a>,
+	DeletePullRequestReviewProtection<'a>,
+	GetCommitSignatureProtection<'a>,
+	CreateCommitSignatureProtection<'a>,
+	DeleteCommitSignatureProtection<'a>,
+	GetStatusChecksProtection<'a>,
+	UpdateStatusCheckProtection<'a>,
+	RemoveStatusCheckProtection<'a>,
+	GetAccessRestrictions<'a>,
+	DeleteAccessRestrictions<'a>,
+	GetAppsWithAccessToTheProtectedBranch<'a>,
+	AddAppAccessRestrictions<'a>,
+	SetAppAccessRestrictions<'a>,
+	RemoveAppAccessRestrictions<'a>,
+	GetTeamsWithAccessToTheProtectedBranch<'a>,
+	AddTeamAccessRestrictions<'a>,
+	SetTeamAccessRestrictions<'a>,
+	RemoveTeamAccessRestrictions<'a>,
+	GetUsersWithAccessToTheProtectedBranch<'a>,
+	AddUserAccessRestrictions<'a>,
+	SetUserAccessRestrictions<'a>,
+	RemoveUserAccessRestrictions<'a>,
+	RenameABranch<'a>,
+	SyncAForkBranchWithTheUpstreamRepository<'a>,
+	MergeABranch<'a>,
+	ListCommits<'a>,
+	ListBranchesForHeadCommit<'a>,
+	ListPullRequestsAssociatedWithACommit<'a>,
+	GetACommit<'a>,
+	CompareTwoCommits<'a>,
+	ListIssuesAssignedToTheAuthenticatedUser<'a>,
+	ListOrganizationIssuesAssignedToTheAuthenticatedUser<'a>,
+	ListAssignees<'a>,
+	CheckIfAUserCanBeAssigned<'a>,
+	ListRepositoryIssues<'a>,
+	CreateAnIssue<'a>,
+	ListIssueCommentsForARepository<'a>,
+	GetAnIssueComment<'a>,
+	UpdateAnIssueComment<'a>,
+	DeleteAnIssueComment<'a>,
+	ListIssueEventsForARepository<'a>,
+	GetAnIssueEvent<'a>,
+	GetAnIssue<'a>,
+	UpdateAnIssue<'a>,
+	AddAssigneesToAnIssue<'a>,
+	RemoveAssigneesFromAnIssue<'a>,
+	CheckIfAUserCanBeAssignedToAIssue<'a>,
+	ListIssueComments<'a>,
+	CreateAnIssueComment<'a>,
+	ListIssueEvents<'a>,
+	ListLabelsForAnIssue<'a>,
+	AddLabelsToAnIssue<'a>,
+	SetLabelsForAnIssue<'a>,
+	RemoveAllLabelsFromAnIssue<'a>,
+	RemoveALabelFromAnIssue<'a>,
+	LockAnIssue<'a>,
+	UnlockAnIssue<'a>,
+	ListTimelineEventsForAnIssue<'a>,
+	ListLabelsForARepository<'a>,
+	CreateALabel<'a>,
+	GetALabel<'a>,
+	UpdateALabel<'a>,
+	DeleteALabel<'a>,
+	ListMilestones<'a>,
+	CreateAMilestone<'a>,
+	GetAMilestone<'a>,
+	UpdateAMilestone<'a>,
+	DeleteAMilestone<'a>,
+	ListLabelsForIssuesInAMilestone<'a>,
+	ListUserAccountIssuesAssignedToTheAuthenticatedUser<'a>,
+	ListPullRequests<'a>,
+	CreateAPullRequest<'a>,
+	GetAPullRequest<'a>,
+	UpdateAPullRequest<'a>,
+	ListCommitsOnAPullRequest<'a>,
+	ListPullRequestsFiles<'a>,
+	CheckIfAPullRequestHasBeenMerged<'a>,
+	MergeAPullRequest<'a>,
+	UpdateAPullRequestBranch<'a>,
+	ListReviewCommentsInARepository<'a>,
+	GetAReviewCommentForAPullRequest<'a>,
+	UpdateAReviewCommentForAPullRequest<'a>,
+	DeleteAReviewCommentForAPullRequest<'a>,
+	ListReviewCommentsOnAPullRequest<'a>,
+	CreateAReviewCommentForAPullRequest<'a>,
+	CreateAReplyForAReviewComment<'a>,
+	GetAllRequestedReviewersForAPullRequest<'a>,
+	RequestReviewersForAPullRequest<'a>,
+	RemoveRequestedReviewersFromAPullRequest<'a>,
+	ListReviewsForAPullRequest<'a>,
+	CreateAReviewForAPullRequest<'a>,
+	GetAReviewForAPullRequest<'a>,
+	UpdateAReviewForAPullRequest<'a>,
+	DeleteAPendingReviewForAPullRequest<'a>,
+	ListCommentsForAPullRequestReview<'a>,
+	DismissAReviewForAPullRequest<'a>,
+	SubmitAReviewForAPullRequest<'a>,
+	ListReactionsForACommitComment<'a>,
+	CreateReactionForACommitComment<'a>,
+	DeleteACommitCommentReaction<'a>,
+	ListReactionsForAnIssueComment<'a>,
+	CreateReactionForAnIssueComment<'a>,
+	DeleteAnIssueCommentReaction<'a>,
+	ListReactionsForAnIssue<'a>,
+	CreateReactionForAnIssue<'a>,
+	DeleteAnIssueReaction<'a>,
+	ListReactionsForAPullRequestReviewComment<'a>,
+	CreateReactionForAPullRequestReviewComment<'a>,
+	DeleteAPullRequestCommentReaction<'a>,
+	ListReactionsForARelease<'a>,
+	CreateReactionForARelease<'a>,
+	DeleteAReleaseReaction<'a>,
+	ListReleases<'a>,
+	CreateARelease<'a>,
+	GetAReleaseAsset<'a>,
+	UpdateAReleaseAsset<'a>,
+	DeleteAReleaseAsset<'a>,
+	GenerateReleaseNotesContentForARelease<'a>,
+	GetTheLatestRelease<'a>,
+	GetAReleaseByTagName<'a>,
+	GetARelease<'a>,
+	UpdateARelease<'a>,
+	DeleteARelease<'a>,
+	ListReleaseAssets<'a>,
+	UploadAReleaseAsset<'a>,
+	DownloadARepositoryArchiveTar<'a>,
+	DownloadARepositoryArchiveZip<'a>,
+	ListRepositoryTags<'a>,
+	ListOrganizationRepositories<'a>,
+	CreateAnOrganizationRepository<'a>,
+	GetARepository<'a>,
+	UpdateARepository<'a>,
+	DeleteARepository<'a>,
+	EnableDependabotSecurityUpdates<'a>,
+	DisableDependabotSecurityUpdates<'a>,
+	ListCodeownersErrors<'a>,
+	GetRepositoryContent<'a>,
+	CreateOrUpdateFileContents<'a>,
+	DeleteAFile<'a>,
+	ListRepositoryContributors<'a>,
+	CreateARepositoryDispatchEvent<'a>,
+	ListRepositoryLanguages<'a>,
+	GetARepositoryReadme<'a>,
+	GetARepositoryReadmeForADirectory<'a>,
+	ListRepositoryTeams<'a>,
+	GetAllRepositoryTopics<'a>,
+	ReplaceAllRepositoryTopics<'a>,
+	TransferARepository<'a>,
+	CheckIfVulnerabilityAlertsAreEnabledForARepository<'a>,
+	EnableVulnerabilityAlerts<'a>,
+	DisableVulnerabilityAlerts<'a>,
+	CreateARepositoryUsingATemplate<'a>,
+	ListPublicRepositories,
+	ListRepositoriesForTheAuthenticatedUser<'a>,
+	CreateARepositoryForTheAuthenticatedUser<'a>,
+	ListRepositoriesForAUser<'a>,
+}
@@ -0,0 +1,179 @@
+//! Collection of all the supported GitHub REST APIs.
+//!
+//! [`Endpoint`] could hold any request of this crate, which makes it possible to describe
+//! heterogeneous jobs in a file and execute them one by one.
+//!
+//! [`endpoints`] lists the [`EndpointMetadata`] of all of them.
+
+#[cfg(test)] mod test;
+
+mod generated;
+pub use generated::*;
+
+// hack-ink
+use crate::prelude::*;
+
+/// Static description of a GitHub REST API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EndpointMetadata {
+	/// Name of the request struct.
+	pub name: &'static str,
+	/// Category of the API, e.g. `repos`.
+	pub category: &'static str,
+	/// HTTP method.
+	pub method: Method,
+	/// URI template, e.g. `/repos/{owner}/{repo}`.
+	pub uri: &'static str,
+	/// Request's header `Accept`'s value.
+	pub accept: &'static str,
+	/// Path parameters, in order of the URI template.
+	pub path_params: &'static [ParamMetadata],
+	/// Essential payload parameters.
+	pub required_params: &'static [ParamMetadata],
+	/// Optional payload parameters.
+	pub optional_params: &'static [ParamMetadata],
+	/// Request specific headers.
+	pub header_params: &'static [ParamMetadata],
+	/// GitHub documentation URL.
+	pub docs: &'static str,
+	/// Deprecation note, if the API is deprecated.
+	pub deprecated: Option<&'static str>,
+	/// Name of the API preview which the API belongs to.
+	pub preview: Option<&'static str>,
+	/// Host of the API, e.g. `https://api.github.com`.
+	pub base_uri: &'static str,
+	/// Whether the request body is raw binary data, see [`BinaryBody`].
+	pub binary_body: bool,
+	/// Whether the response body is raw binary data, see [`BinaryResponse`].
+	pub binary_response: bool,
+	/// Whether the response is told by the status, see [`BooleanResponse`].
+	pub boolean_response: bool,
+}
+
+/// Static description of a request parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParamMetadata {
+	/// Name of the parameter.
+	///
+	/// This is the header's name for header parameters.
+	pub name: &'static str,
+	/// Rust type of the parameter.
+	pub ty: &'static str,
+}
+
+/// Registry of the APIs, invoked by `generated.rs` which `cargo xtask codegen` writes.
+macro_rules! register_endpoints {
+	($($(#[$meta:meta])* $name:ident $(<$lt:lifetime>)?,)+) => {
+		/// Any request of this crate.
+		#[allow(deprecated)]
+		#[derive(Debug, Clone, PartialEq, Eq)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+		#[cfg_attr(feature = "serde", serde(tag = "endpoint"))]
+		pub enum Endpoint<'a> {
+			$(
+				$(#[$meta])*
+				#[allow(missing_docs)]
+				$name($name $(<$lt>)?),
+			)+
+		}
+		#[allow(deprecated)]
+		impl<'a> Endpoint<'a> {
+			/// Request's header `Accept`'s value.
+			pub fn accept(&self) -> &'static str {
+				match self {
+					$($(#[$meta])* Self::$name(_) => $name::ACCEPT,)+
+				}
+			}
+
+			/// Request's target URI/URL.
+			pub fn api(&self) -> String {
+				match self {
+					$($(#[$meta])* Self::$name(request) => request.api(),)+
+				}
+			}
+
+			/// Request's specific headers.
+			pub fn headers(&self) -> Vec<(&'static str, String)> {
+				match self {
+					$($(#[$meta])* Self::$name(request) => request.headers(),)+
+				}
+			}
+
+			/// Static description of the API.
+			pub fn metadata(&self) -> &'static EndpointMetadata {
+				match self {
+					$($(#[$meta])* Self::$name(_) => &$name::METADATA,)+
+				}
+			}
+
+			/// HTTP method.
+			pub fn method(&self) -> Method {
+				match self {
+					$($(#[$meta])* Self::$name(_) => $name::METHOD,)+
+				}
+			}
+
+			/// Request's payload parameters.
+			pub fn payload_params(&self) -> Vec<(&'static str, Param)> {
+				match self {
+					$($(#[$meta])* Self::$name(request) => request.payload_params(),)+
+				}
+			}
+
+			/// Validate the request's parameters before sending it.
+			pub fn validate(&self) -> Result<(), Error> {
+				match self {
+					$($(#[$meta])* Self::$name(request) => request.validate(),)+
+				}
+			}
+
+			/// Build the request as the client sends it, see `Client::build`.
+			#[cfg(all(test, feature = "client"))]
+			pub(crate) fn build(
+				&self,
+				client: &crate::client::Client,
+				upload: Option<crate::client::Upload>,
+			) -> Result<reqwest::RequestBuilder, Error> {
+				match self {
+					$($(#[$meta])* Self::$name(request) => client.build(request, upload),)+
+				}
+			}
+
+			/// Convert the [`Endpoint`] into an owned instance which doesn't borrow any data.
+			pub fn into_owned(self) -> Endpoint<'static> {
+				match self {
+					$(
+						$(#[$meta])*
+						Self::$name(request) =>
+							Endpoint::$name(register_endpoints!(@into_owned request $($lt)?)),
+					)+
+				}
+			}
+		}
+		/// Static descriptions of all the supported GitHub REST APIs.
+		#[allow(deprecated)]
+		pub fn endpoints() -> &'static [EndpointMetadata] {
+			const ENDPOINTS: &[EndpointMetadata] = &[$($(#[$meta])* $name::METADATA,)+];
+
+			ENDPOINTS
+		}
+		$(
+			$(#[$meta])*
+			#[allow(deprecated)]
+			impl<'a> From<$name $(<$lt>)?> for Endpoint<'a> {
+				fn from(request: $name $(<$lt>)?) -> Self {
+					Self::$name(request)
+				}
+			}
+		)+
+	};
+	(@into_owned $request:ident $lt:lifetime) => {
+		$request.into_owned()
+	};
+	(@into_owned $request:ident) => {
+		$request
+	};
+}
+pub(crate) use register_endpoints;
@@ -0,0 +1,183 @@
+use super::*;
+use crate::api::{commits::*, repos::*};
+
+#[test]
+fn endpoint_should_work() {
+	let endpoint = Endpoint::from(update_a_repository("hack-ink", "githuber").archived(true));
+
+	assert_eq!(endpoint.api(), "https://api.github.com/repos/hack-ink/githuber");
+	assert_eq!(endpoint.method(), Method::Patch);
+	assert_eq!(endpoint.payload_params(), vec![("archived", Param::Bool(true))]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_endpoints_should_work() {
+	let endpoints = serde_json::from_str::<Vec<Endpoint>>(
+		r#"[
+			{
+				"endpoint": "UpdateARepository",
+				"owner": "hack-ink",
+				"repo": "githuber",
+				"visibility": "private",
+				"squash_merge_commit_title": "PR_TITLE"
+			},
+			{
+				"endpoint": "ListOrganizationRepositories",
+				"org": "hack-ink",
+				"type": "sources"
+			}
+		]"#,
+	)
+	.unwrap();
+
+	assert_eq!(
+		endpoints,
+		vec![
+			Endpoint::from(
+				update_a_repository("hack-ink", "githuber")
+					.visibility(Visibility::Private)
+					.squash_merge_commit_title(SquashMergeCommitTitle::PrTitle)
+			),
+			Endpoint::from(list_organization_repositories("hack-ink").r#type(OrgRepoType::Sources)),
+		]
+	);
+	assert_eq!(
+		serde_json::to_value(&endpoints[1]).unwrap(),
+		serde_json::json!({ "endpoint": "ListOrganizationRepositories", "org": "hack-ink", "type": "sources" })
+	);
+}
+
+#[test]
+fn endpoints_should_work() {
+	let endpoints = endpoints();
+	let mut names = endpoints.iter().map(|e| e.name).collect::<Vec<_>>();
+
+	names.sort_unstable();
+	names.dedup();
+
+	assert_eq!(names.len(), endpoints.len());
+
+	let metadata = endpoints.iter().find(|e| e.name == "GetACommit").unwrap();
+
+	assert_eq!(metadata.category, "commits");
+	assert_eq!(metadata.method, Method::Get);
+	assert_eq!(metadata.uri, "/repos/{owner}/{repo}/commits/{ref}");
+	assert_eq!(
+		metadata.path_params,
+		[
+			ParamMetadata { name: "owner", ty: "&'a str" },
+			ParamMetadata { name: "repo", ty: "&'a str" },
+			ParamMetadata { name: "ref", ty: "&'a str" },
+		]
+	);
+	assert_eq!(
+		metadata.optional_params,
+		[ParamMetadata { name: "per_page", ty: "u8" }, ParamMetadata { name: "page", ty: "u16" }]
+	);
+	assert_eq!(metadata.docs, "https://docs.github.com/en/rest/commits/commits#get-a-commit");
+	assert_eq!(Endpoint::from(get_a_commit("hack-ink", "githuber", "main")).metadata(), metadata);
+}
+
+#[test]
+#[allow(deprecated)]
+fn properties_should_work() {
+	#[api_impl::api]
+	#[properties(
+		category = "repos",
+		method = "GET",
+		accept = "application/vnd.github.mercy-preview+json",
+		uri = "/repos/{}/{}/topics",
+		docs = "https://docs.github.com/en/rest/repos/repos#get-all-repository-topics",
+		deprecated = "use `GetAllRepositoryTopics` instead",
+		preview = "mercy"
+	)]
+	struct GetAllRepositoryTopicsPreview<'a> {
+		#[path_param]
+		owner: &'a str,
+		#[path_param]
+		repo: &'a str,
+	}
+
+	let metadata = GetAllRepositoryTopicsPreview::METADATA;
+
+	assert_eq!(
+		metadata.docs,
+		"https://docs.github.com/en/rest/repos/repos#get-all-repository-topics"
+	);
+	assert_eq!(metadata.deprecated, Some("use `GetAllRepositoryTopics` instead"));
+	assert_eq!(metadata.preview, Some("mercy"));
+	assert_eq!(
+		get_all_repository_topics_preview("hack-ink", "githuber").api(),
+		"https://api.github.com/repos/hack-ink/githuber/topics"
+	);
+
+	let metadata = endpoints().iter().find(|e| e.name == "ListCodeownersErrors").unwrap();
+
+	assert_eq!(metadata.docs, "https://docs.github.com/en/rest/repos/repos#list-codeowners-errors");
+	assert_eq!(metadata.deprecated, None);
+	assert_eq!(metadata.preview, None);
+}
+
+/// Build the request of every endpoint in `fixtures.json` with the client, and compare them with
+/// `snapshots.json`.
+///
+/// Run with `BLESS=1` to accept the current requests.
+#[cfg(all(feature = "serde", feature = "client"))]
+#[test]
+fn snapshots_should_match() {
+	let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/api/endpoint/snapshots.json");
+	let fixtures = serde_json::from_str::<Vec<Endpoint>>(include_str!("fixtures.json")).unwrap();
+
+	for metadata in endpoints() {
+		assert!(
+			fixtures.iter().any(|e| e.metadata().name == metadata.name),
+			"add a fixture of `{}` to `fixtures.json`",
+			metadata.name
+		);
+	}
+
+	let client = crate::client::Client::new();
+	let snapshots = fixtures.iter().map(|e| snapshot(&client, e)).collect::<Vec<_>>();
+
+	if std::env::var_os("BLESS").is_some() {
+		std::fs::write(path, serde_json::to_string_pretty(&snapshots).unwrap() + "\n").unwrap();
+	}
+
+	assert_eq!(
+		snapshots,
+		serde_json::from_str::<Vec<serde_json::Value>>(&std::fs::read_to_string(path).unwrap())
+			.unwrap(),
+		"requests changed, run with `BLESS=1` to update the snapshots"
+	);
+}
+
+#[cfg(all(feature = "serde", feature = "client"))]
+fn snapshot(client: &crate::client::Client, endpoint: &Endpoint) -> serde_json::Value {
+	let upload = endpoint
+		.metadata()
+		.binary_body
+		.then(|| crate::client::Upload::bytes("githuber", "text/plain"));
+	let request = endpoint.build(client, upload).unwrap().build().unwrap();
+	let headers = request
+		.headers()
+		.iter()
+		// It changes with the crate's version.
+		.filter(|(k, _)| *k != reqwest::header::USER_AGENT)
+		.map(|(k, v)| (k.as_str().to_owned(), v.to_str().unwrap().into()))
+		.collect::<serde_json::Map<_, _>>();
+	let body = request.body().map(|body| {
+		let body = body.as_bytes().unwrap();
+
+		serde_json::from_slice::<serde_json::Value>(body)
+			.unwrap_or_else(|_| String::from_utf8_lossy(body).into_owned().into())
+	});
+
+	serde_json::json!({
+		"endpoint": endpoint.metadata().name,
+		"method": request.method().as_str(),
+		"url": request.url().as_str(),
+		"headers": headers,
+		"body": body,
+	})
+}
@@ -11,6 +11,8 @@ pub trait Api {
 
 /// Extended GitHub REST API.
 pub trait ApiExt: Api {
+	/// Static description of the API.
+	const METADATA: EndpointMetadata;
 	/// HTTP method.
 	const METHOD: Method;
 
@@ -19,7 +21,204 @@ pub trait ApiExt: Api {
 	/// Can be one of:
 	/// - Body parameters
 	/// - Query parameters
-	fn payload_params(&self) -> Vec<(&'static str, String)>;
+	fn payload_params(&self) -> Vec<(&'static str, Param)>;
+
+	/// Request's specific headers.
+	fn headers(&self) -> Vec<(&'static str, String)> {
+		Vec::new()
+	}
+
+	/// Validate the request's parameters before sending it.
+	fn validate(&self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+/// APIs whose request body is raw binary data instead of JSON.
+pub trait BinaryBody: ApiExt {}
+
+/// APIs whose response body is raw binary data instead of JSON.
+pub trait BinaryResponse: ApiExt {}
+
+/// APIs whose response is told by the status instead of a body.
+pub trait BooleanResponse: ApiExt {}
+
+/// Main error.
+pub enum Error {
+	/// A parameter's value is out of its accepted range.
+	OutOfRange {
+		/// Name of the parameter.
+		param: &'static str,
+		/// Given value.
+		value: String,
+		/// Accepted range.
+		range: &'static str,
+	},
+	/// Two parameters can't be used together.
+	Conflict {
+		/// Name of the parameter.
+		param: &'static str,
+		/// Name of the conflicting parameter.
+		conflicts_with: &'static str,
+	},
+}
+
+/// Serialized value of a request parameter.
+pub enum Param {
+	/// Boolean value.
+	Bool(bool),
+	/// Integer value.
+	Integer(i64),
+	/// String value.
+	String(String),
+}
+
+/// Types which could be used as a request parameter.
+pub trait ToParam {
+	/// Serialize `self` into a [`Param`].
+	fn to_param(&self) -> Param;
+}
+impl<T> ToParam for &T
+where
+	T: ?Sized + ToParam,
+{
+	fn to_param(&self) -> Param {
+		T::to_param(self)
+	}
+}
+impl ToParam for bool {
+	fn to_param(&self) -> Param {
+		Param::Bool(*self)
+	}
+}
+impl ToParam for str {
+	fn to_param(&self) -> Param {
+		Param::String(self.to_owned())
+	}
+}
+impl std::fmt::Display for Param {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::Bool(v) => v.fmt(f),
+			Self::Integer(v) => v.fmt(f),
+			Self::String(v) => v.fmt(f),
+		}
+	}
+}
+impl ToParam for std::borrow::Cow<'_, str> {
+	fn to_param(&self) -> Param {
+		Param::String(self.as_ref().to_owned())
+	}
+}
+impl ToParam for u8 {
+	fn to_param(&self) -> Param {
+		Param::Integer((*self).into())
+	}
+}
+
+/// Percent-encode a path parameter.
+pub fn encode_path_param(value: impl std::fmt::Display, multi_segment: bool) -> String {
+	value
+		.to_string()
+		.bytes()
+		.map(|b| match b {
+			b'/' if multi_segment => "/".into(),
+			b if b.is_ascii_alphanumeric() || b"-._~".contains(&b) => (b as char).to_string(),
+			b => format!("%{b:02X}"),
+		})
+		.collect()
+}
+
+/// Types which could be converted into a `'static` version of themselves.
+pub trait IntoStatic {
+	/// The `'static` version of `Self`.
+	type Static: 'static;
+
+	/// Convert `self` into [`Self::Static`].
+	fn into_static(self) -> Self::Static;
+}
+impl<T> IntoStatic for Option<T>
+where
+	T: IntoStatic,
+{
+	type Static = Option<T::Static>;
+
+	fn into_static(self) -> Self::Static {
+		self.map(T::into_static)
+	}
+}
+impl IntoStatic for std::borrow::Cow<'_, str> {
+	type Static = std::borrow::Cow<'static, str>;
+
+	fn into_static(self) -> Self::Static {
+		std::borrow::Cow::Owned(self.into_owned())
+	}
+}
+impl IntoStatic for bool {
+	type Static = Self;
+
+	fn into_static(self) -> Self::Static {
+		self
+	}
+}
+impl IntoStatic for u8 {
+	type Static = Self;
+
+	fn into_static(self) -> Self::Static {
+		self
+	}
+}
+
+/// Visibility of a repository.
+#[api_impl::param]
+pub enum Visibility {
+	Public,
+	Private,
+	Internal,
+}
+
+/// Static description of a GitHub REST API.
+pub struct EndpointMetadata {
+	/// Name of the request struct.
+	pub name: &'static str,
+	/// Category of the API, e.g. `repos`.
+	pub category: &'static str,
+	/// HTTP method.
+	pub method: Method,
+	/// URI template, e.g. `/repos/{owner}/{repo}`.
+	pub uri: &'static str,
+	/// Request's header `Accept`'s value.
+	pub accept: &'static str,
+	/// Path parameters, in order of the URI template.
+	pub path_params: &'static [ParamMetadata],
+	/// Essential payload parameters.
+	pub required_params: &'static [ParamMetadata],
+	/// Optional payload parameters.
+	pub optional_params: &'static [ParamMetadata],
+	/// Request specific headers.
+	pub header_params: &'static [ParamMetadata],
+	/// GitHub documentation URL.
+	pub docs: &'static str,
+	/// Deprecation note, if the API is deprecated.
+	pub deprecated: Option<&'static str>,
+	/// Name of the API preview which the API belongs to.
+	pub preview: Option<&'static str>,
+	/// Host of the API, e.g. `https://api.github.com`.
+	pub base_uri: &'static str,
+	/// Whether the request body is raw binary data, see [`BinaryBody`].
+	pub binary_body: bool,
+	/// Whether the response body is raw binary data, see [`BinaryResponse`].
+	pub binary_response: bool,
+	/// Whether the response is told by the status, see [`BooleanResponse`].
+	pub boolean_response: bool,
+}
+
+/// Static description of a request parameter.
+pub struct ParamMetadata {
+	/// Name of the parameter.
+	pub name: &'static str,
+	/// Rust type of the parameter.
+	pub ty: &'static str,
 }
 
 /// HTTP methods.
@@ -48,8 +247,9 @@ pub struct CreateAnOrganizationRepository<'a> {
 	pub name: &'a str,
 	pub description: Option<&'a str>,
 	pub homepage: Option<&'a str>,
+	#[conflicts_with(visibility)]
 	pub private: Option<bool>,
-	pub visibility: Option<&'a str>,
+	pub visibility: Option<Visibility>,
 	pub has_issues: Option<bool>,
 	pub has_projects: Option<bool>,
 	pub has_wiki: Option<bool>,
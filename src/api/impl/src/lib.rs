@@ -19,6 +19,12 @@ enum ApiProperty {
 	Method(String),
 	Accept(String),
 	Uri(String),
+	Docs(String),
+	Deprecated(String),
+	Preview(String),
+	BaseUri(String),
+	Body(String),
+	Response(String),
 }
 impl Parse for ApiProperty {
 	fn parse(input: ParseStream) -> Result<Self> {
@@ -40,8 +46,14 @@ impl Parse for ApiProperty {
 			"method" => ApiProperty::Method(value),
 			"accept" => ApiProperty::Accept(value),
 			"uri" => ApiProperty::Uri(value),
+			"docs" => ApiProperty::Docs(value),
+			"deprecated" => ApiProperty::Deprecated(value),
+			"preview" => ApiProperty::Preview(value),
+			"base_uri" => ApiProperty::BaseUri(value),
+			"body" => ApiProperty::Body(value),
+			"response" => ApiProperty::Response(value),
 			property => panic!(
-				"expect one of the [\"category\", \"method\", \"accept\", \"uri\"] but found {property:?}"
+				"expect one of the [\"category\", \"method\", \"accept\", \"uri\", \"docs\", \"deprecated\", \"preview\", \"base_uri\", \"body\", \"response\"] but found {property:?}"
 			),
 		})
 	}
@@ -49,6 +61,38 @@ impl Parse for ApiProperty {
 
 /// Generate a modern ergonomic GitHub REST API.
 ///
+/// # Properties
+/// - `category`, `method`, `accept` and `uri`: essential, see the example below.
+/// - `docs = "..."`: GitHub documentation URL, defaults to `https://docs.github.com/en/rest/{category}/{category}#{kebab-case-name}`.
+/// - `deprecated = "..."`: mark the API as `#[deprecated]` with the given note.
+/// - `preview = "..."`: name of the API preview which the API belongs to.
+/// - `base_uri = "..."`: host of the API, defaults to `https://api.github.com`.
+/// - `body = "binary"`: the request body is raw binary data, implement `BinaryBody`.
+/// - `response = "binary"`: the response body is raw binary data, implement `BinaryResponse`.
+/// - `response = "boolean"`: the response is told by the status, implement `BooleanResponse`.
+///
+/// # Field attributes
+/// - `#[path_param]`: a parameter of the URI, filled into the `{}`s in order and percent-encoded.
+///   `#[path_param(multi_segment)]` keeps the slashes, e.g. of a file path.
+/// - `#[payload_ess_param]`: an essential body/query parameter.
+/// - `#[header_param("Header-Name")]`: a request specific header, essential unless it's an
+///   `Option`.
+/// - `#[range(..)]`: reject the value if it's out of the range in `ApiExt::validate`.
+/// - `#[conflicts_with(..)]`: reject the request if this parameter is used together with any of the
+///   given ones in `ApiExt::validate`.
+/// - `#[repeated]`: send a list parameter as repeated `name[]=..` query pairs instead of a
+///   comma-joined value.
+/// - `#[into]`: the constructor and setters accept `impl Into<..>` of the parameter, `&'a str`s
+///   always do.
+/// - `#[validate]`: check the fields of a nested parameter with its `Validate` implementation in
+///   `ApiExt::validate`.
+/// - `#[rename = "..."]`: name of the parameter, if it isn't the field's name, e.g. `self`.
+///
+/// Fields without `#[path_param]`, `#[payload_ess_param]` or `#[header_param]` are optional
+/// body/query parameters and must be an `Option`.
+///
+/// Doc comments on the struct are kept, followed by its GitHub reference(s).
+///
 /// # Example
 /// ```ignore
 /// use githuber::prelude::*;
@@ -63,9 +107,10 @@ impl Parse for ApiProperty {
 /// pub struct ListOrganizationRepositories<'a> {
 /// 	#[path_param]
 /// 	pub org: &'a str,
-/// 	pub r#type: Option<&'a str>,
-/// 	pub sort: Option<&'a str>,
-/// 	pub direction: Option<&'a str>,
+/// 	pub r#type: Option<OrgRepoType>,
+/// 	pub sort: Option<RepoSort>,
+/// 	pub direction: Option<Direction>,
+/// 	#[range(1..=100)]
 /// 	pub per_page: Option<u8>,
 /// 	pub page: Option<u16>,
 /// }
@@ -85,7 +130,7 @@ impl Parse for ApiProperty {
 /// 	pub description: Option<&'a str>,
 /// 	pub homepage: Option<&'a str>,
 /// 	pub private: Option<bool>,
-/// 	pub visibility: Option<&'a str>,
+/// 	pub visibility: Option<Visibility>,
 /// 	pub has_issues: Option<bool>,
 /// 	pub has_projects: Option<bool>,
 /// 	pub has_wiki: Option<bool>,
@@ -100,10 +145,10 @@ impl Parse for ApiProperty {
 /// 	pub allow_auto_merge: Option<bool>,
 /// 	pub delete_branch_on_merge: Option<bool>,
 /// 	pub use_squash_pr_title_as_default: Option<bool>,
-/// 	pub squash_merge_commit_title: Option<&'a str>,
-/// 	pub squash_merge_commit_message: Option<&'a str>,
-/// 	pub merge_commit_title: Option<&'a str>,
-/// 	pub merge_commit_message: Option<&'a str>,
+/// 	pub squash_merge_commit_title: Option<SquashMergeCommitTitle>,
+/// 	pub squash_merge_commit_message: Option<SquashMergeCommitMessage>,
+/// 	pub merge_commit_title: Option<MergeCommitTitle>,
+/// 	pub merge_commit_message: Option<MergeCommitMessage>,
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -120,11 +165,23 @@ pub fn api(_: TokenStream, input: TokenStream) -> TokenStream {
 	// #[cfg(feature = "debug")]
 	// dbg!(&api_attrs);
 
+	// Keep the struct's own docs above the generated GitHub reference(s).
+	let api_doc_attrs =
+		api_attrs.iter().filter(|attr| attr.path().is_ident("doc")).cloned().collect::<Vec<_>>();
+	let api_doc_separator = (!api_doc_attrs.is_empty()).then(|| quote::quote!(#[doc = ""]));
+
 	let api_name = api_struct.ident;
-	let mut api_doc = String::new();
+	let mut api_category = String::new();
+	let mut api_docs = String::new();
 	let mut api_method = String::new();
 	let mut api_accept = String::new();
 	let mut api_uri = String::new();
+	let mut api_deprecated = None;
+	let mut api_preview = None;
+	let mut api_base_uri = None;
+	let mut api_binary_body = false;
+	let mut api_binary_response = false;
+	let mut api_boolean_response = false;
 
 	api_attrs
 		.into_iter()
@@ -135,24 +192,83 @@ pub fn api(_: TokenStream, input: TokenStream) -> TokenStream {
 				.into_iter()
 		})
 		.for_each(|property| match property {
-			ApiProperty::Category(category) =>
-				api_doc = format!(
-					" - <https://docs.github.com/en/rest/{category}/{category}#{}>",
-					api_name.to_string().to_case(Case::Kebab)
-				),
+			ApiProperty::Category(category) => api_category = category,
 			ApiProperty::Method(method) => api_method = method,
 			ApiProperty::Accept(accept) => api_accept = accept,
-			ApiProperty::Uri(uri) => api_uri = format!("{{}}{uri}"),
+			ApiProperty::Uri(uri) => api_uri = uri,
+			ApiProperty::Docs(docs) => api_docs = docs,
+			ApiProperty::Deprecated(note) => api_deprecated = Some(note),
+			ApiProperty::Preview(preview) => api_preview = Some(preview),
+			ApiProperty::BaseUri(base_uri) => api_base_uri = Some(base_uri),
+			ApiProperty::Body(body) => match body.as_str() {
+				"json" => api_binary_body = false,
+				"binary" => api_binary_body = true,
+				body => panic!("expect one of the [\"json\", \"binary\"] but found {body:?}"),
+			},
+			ApiProperty::Response(response) => match response.as_str() {
+				"json" => (api_binary_response, api_boolean_response) = (false, false),
+				"binary" => (api_binary_response, api_boolean_response) = (true, false),
+				"boolean" => (api_binary_response, api_boolean_response) = (false, true),
+				response => panic!(
+					"expect one of the [\"json\", \"binary\", \"boolean\"] but found {response:?}"
+				),
+			},
 		});
 
+	if api_docs.is_empty() {
+		api_docs = format!(
+			"https://docs.github.com/en/rest/{api_category}/{api_category}#{}",
+			api_name.to_string().to_case(Case::Kebab)
+		);
+	}
+
+	let api_doc = format!(" - <{api_docs}>");
+	let api_preview_doc = api_preview.as_ref().map(|preview| {
+		let doc = format!(" Part of the `{preview}` API preview.");
+
+		quote::quote! {
+			///
+			/// # Preview
+			#[doc = #doc]
+		}
+	});
+	let api_deprecated_attr =
+		api_deprecated.as_ref().map(|note| quote::quote!(#[deprecated(note = #note)]));
+	let api_deprecated_metadata = match &api_deprecated {
+		Some(note) => quote::quote!(Some(#note)),
+		None => quote::quote!(None),
+	};
+	let api_preview_metadata = match &api_preview {
+		Some(preview) => quote::quote!(Some(#preview)),
+		None => quote::quote!(None),
+	};
+	let api_base_uri =
+		api_base_uri.map(|base_uri| quote::quote!(const BASE_URI: &'static str = #base_uri;));
+
 	let api_vis = api_struct.vis;
 	let api_generics = api_struct.generics;
 	let mut api_path_params = Vec::new();
 	let mut api_path_params_tys = Vec::new();
+	let mut api_path_params_multi_segment = Vec::new();
 	let mut api_payload_ess_params = Vec::new();
 	let mut api_payload_ess_params_tys = Vec::new();
+	let mut api_payload_ess_params_names = Vec::new();
 	let mut api_payload_opt_params = Vec::new();
 	let mut api_payload_opt_params_tys = Vec::new();
+	let mut api_payload_opt_params_names = Vec::new();
+	let mut api_header_ess_params = Vec::new();
+	let mut api_header_ess_params_tys = Vec::new();
+	let mut api_header_ess_params_names = Vec::new();
+	let mut api_header_opt_params = Vec::new();
+	let mut api_header_opt_params_tys = Vec::new();
+	let mut api_header_opt_params_names = Vec::new();
+	let mut api_validations = Vec::new();
+	let mut api_path_params_metadata = Vec::new();
+	let mut api_payload_ess_params_metadata = Vec::new();
+	let mut api_payload_opt_params_metadata = Vec::new();
+	let mut api_header_params_metadata = Vec::new();
+	// Parameters whose constructor and setters accept `impl Into<..>`.
+	let mut api_into_params = Vec::new();
 
 	{
 		let Fields::Named(fields) = api_struct.fields else {
@@ -160,107 +276,301 @@ pub fn api(_: TokenStream, input: TokenStream) -> TokenStream {
 		};
 
 		fields.named.into_iter().for_each(|field| {
-			if field.attrs.is_empty() {
-				let Type::Path(path) = field.ty else {
-					panic!("expect a `Path` here");
-				};
-
-				if &path.path.segments[0].ident.to_string() == "Option" {
-					api_payload_opt_params.push(field.ident);
+			let mut kind = None;
+			let mut header = None;
+			let mut range = None;
+			let mut conflicts = Vec::new();
+			let mut repeated = false;
+			let mut into = false;
+			let mut multi_segment = false;
+			let mut rename = None;
+			let mut validate = false;
 
-					let PathArguments::AngleBracketed(args) = &path.path.segments[0].arguments
-					else {
-						panic!("expect a `PathArguments::AngleBracketed` here");
-					};
-					let GenericArgument::Type(ty) = &args.args[0] else {
-						panic!("expect a `GenericArgument::Type` here");
-					};
+			field.attrs.iter().for_each(|attr| {
+				match attr.path().get_ident().unwrap().to_string().as_str() {
+					ident @ ("path_param" | "payload_ess_param") => {
+						kind = Some(ident.to_owned());
 
-					api_payload_opt_params_tys.push(ty.to_owned());
-				} else {
-					panic!("expect an `Option` here");
-				}
-			} else {
-				match field.attrs[0].path().get_ident().unwrap().to_string().as_str() {
-					"path_param" => {
-						api_path_params.push(field.ident);
-						api_path_params_tys.push(field.ty);
-					},
-					"payload_ess_param" => {
-						api_payload_ess_params.push(field.ident);
-						api_payload_ess_params_tys.push(field.ty);
+						if let Meta::List(list) = &attr.meta {
+							match list.parse_args::<Ident>().unwrap().to_string().as_str() {
+								"multi_segment" if ident == "path_param" => multi_segment = true,
+								arg => panic!("expect `multi_segment` of `path_param` but found {arg:?}"),
+							}
+						}
 					},
+					"header_param" => header = Some(attr.parse_args::<LitStr>().unwrap().value()),
+					"range" => range = Some(attr.parse_args::<Expr>().unwrap()),
+					"conflicts_with" => conflicts.extend(
+						attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+							.unwrap(),
+					),
+					"repeated" => repeated = true,
+					"into" => into = true,
+					"rename" => rename = Some(rename_value(attr)),
+					"validate" => validate = true,
 					ident => panic!(
-						"expect one of the [\"path_param\", \"payload_ess_param\"] but found {ident:?}"
+						"expect one of the [\"path_param\", \"payload_ess_param\", \"header_param\", \"range\", \"conflicts_with\", \"repeated\", \"into\", \"rename\", \"validate\"] but found {ident:?}"
 					),
 				}
+			});
+
+			let field_ident = field.ident.clone().unwrap();
+			let field_name = rename
+				.unwrap_or_else(|| field_ident.to_string().trim_start_matches("r#").to_owned());
+			let field_opt_ty = option_inner(&field.ty);
+			// Name of the payload parameter, `[]` makes the query repeat it for each item.
+			let param_name = if repeated { format!("{field_name}[]") } else { field_name.clone() };
+
+			// `&'a str`s are stored as `Cow`s, which always take `impl Into<..>`.
+			if into || is_str_ref(field_opt_ty.as_ref().unwrap_or(&field.ty)) {
+				api_into_params.push(field_ident.clone());
+			}
+
+			if repeated && (kind.as_deref() == Some("path_param") || header.is_some()) {
+				panic!("expect `repeated` on a payload parameter");
+			}
+
+			if let Some(range) = range {
+				let range_repr = quote::quote!(#range).to_string().replace(' ', "");
+				let check = quote::quote! {
+					if !(#range).contains(value) {
+						return Err(Error::OutOfRange {
+							param: #field_name,
+							value: value.to_param().to_string(),
+							range: #range_repr,
+						});
+					}
+				};
+
+				api_validations.push(if field_opt_ty.is_none() {
+					quote::quote! {
+						let value = &self.#field_ident;
+
+						#check
+					}
+				} else {
+					quote::quote! {
+						if let Some(value) = &self.#field_ident {
+							#check
+						}
+					}
+				});
+			}
+
+			if validate {
+				api_validations.push(if field_opt_ty.is_none() {
+					quote::quote! {
+						Validate::validate(&self.#field_ident)?;
+					}
+				} else {
+					quote::quote! {
+						if let Some(value) = &self.#field_ident {
+							Validate::validate(value)?;
+						}
+					}
+				});
+			}
+
+			conflicts.into_iter().for_each(|conflict| {
+				if field_opt_ty.is_none() {
+					panic!("expect `conflicts_with` on an optional parameter");
+				}
+
+				let conflict_name = conflict.to_string().trim_start_matches("r#").to_owned();
+
+				api_validations.push(quote::quote! {
+					if self.#field_ident.is_some() && self.#conflict.is_some() {
+						return Err(Error::Conflict {
+							param: #field_name,
+							conflicts_with: #conflict_name,
+						});
+					}
+				});
+			});
+
+			match (kind.as_deref(), header, field_opt_ty) {
+				(Some("path_param"), None, _) => {
+					api_path_params_metadata.push(param_metadata(&field_name, &field.ty));
+					api_path_params.push(field.ident);
+					api_path_params_tys.push(field.ty);
+					api_path_params_multi_segment.push(multi_segment);
+				},
+				(Some(_), None, _) => {
+					api_payload_ess_params_metadata.push(param_metadata(&param_name, &field.ty));
+					api_payload_ess_params_names.push(param_name);
+					api_payload_ess_params.push(field.ident);
+					api_payload_ess_params_tys.push(field.ty);
+				},
+				(None, Some(header), None) => {
+					api_header_params_metadata.push(param_metadata(&header, &field.ty));
+					api_header_ess_params.push(field.ident);
+					api_header_ess_params_tys.push(field.ty);
+					api_header_ess_params_names.push(header);
+				},
+				(None, Some(header), Some(ty)) => {
+					api_header_params_metadata.push(param_metadata(&header, &field.ty));
+					api_header_opt_params.push(field.ident);
+					api_header_opt_params_tys.push(ty);
+					api_header_opt_params_names.push(header);
+				},
+				(None, None, Some(ty)) => {
+					api_payload_opt_params_metadata.push(param_metadata(&param_name, &ty));
+					api_payload_opt_params_names.push(param_name);
+					api_payload_opt_params.push(field.ident);
+					api_payload_opt_params_tys.push(ty);
+				},
+				(None, None, None) => panic!("expect an `Option` here"),
+				_ => panic!("expect `header_param` to be used alone"),
 			}
 		});
 	}
 
+	let api_uri_template = api_path_params.iter().fold(api_uri.clone(), |uri, param| {
+		let name = param.as_ref().unwrap().to_string();
+
+		uri.replacen("{}", &format!("{{{}}}", name.trim_start_matches("r#")), 1)
+	});
+	let api_uri = format!("{{}}{api_uri}");
 	let api_method = quote::format_ident!("{}", api_method.to_case(Case::Pascal));
-	let get_names = |params: &[Option<Ident>]| {
+	let api_name_snake_case = quote::format_ident!("{}", api_name.to_string().to_case(Case::Snake));
+	// Essential parameters are the constructor's arguments, in order of:
+	// path parameters, payload parameters and header parameters.
+	let api_ess_params = api_path_params
+		.iter()
+		.chain(&api_payload_ess_params)
+		.chain(&api_header_ess_params)
+		.cloned()
+		.collect::<Vec<_>>();
+	let api_ess_params_tys = api_path_params_tys
+		.into_iter()
+		.chain(api_payload_ess_params_tys)
+		.chain(api_header_ess_params_tys)
+		.map(cow_str)
+		.collect::<Vec<_>>();
+	let api_opt_params =
+		api_payload_opt_params.iter().chain(&api_header_opt_params).cloned().collect::<Vec<_>>();
+	let api_opt_params_tys = api_payload_opt_params_tys
+		.into_iter()
+		.chain(api_header_opt_params_tys)
+		.map(cow_str)
+		.collect::<Vec<_>>();
+	let takes_into =
+		|param: &Option<Ident>| param.as_ref().is_some_and(|param| api_into_params.contains(param));
+	let get_args = |params: &[Option<Ident>], tys: &[Type]| {
 		params
 			.iter()
-			.map(|field| {
-				field.as_ref().map(|field| field.to_string().trim_start_matches("r#").to_owned())
+			.zip(tys)
+			.map(|(param, ty)| {
+				if takes_into(param) {
+					(quote::quote!(impl Into<#ty>), quote::quote!(#param.into()))
+				} else {
+					(quote::quote!(#ty), quote::quote!(#param))
+				}
 			})
-			.collect::<Vec<_>>()
+			.unzip::<_, _, Vec<_>, Vec<_>>()
 	};
-	let api_payload_ess_params_names = get_names(&api_payload_ess_params);
-	let api_payload_opt_params_names = get_names(&api_payload_opt_params);
-	let api_name_snake_case = quote::format_ident!("{}", api_name.to_string().to_case(Case::Snake));
+	let (api_ess_params_args, api_ess_params_values) =
+		get_args(&api_ess_params, &api_ess_params_tys);
+	let (api_opt_params_args, api_opt_params_values) =
+		get_args(&api_opt_params, &api_opt_params_tys);
+	let api_ess_params_inits = api_ess_params
+		.iter()
+		.zip(&api_ess_params_tys)
+		.map(|(param, _)| {
+			if takes_into(param) {
+				quote::quote!(#param: #param.into())
+			} else {
+				quote::quote!(#param)
+			}
+		})
+		.collect::<Vec<_>>();
+	let api_generics_params = &api_generics.params;
+	let api_binary_body_impl = api_binary_body.then(|| {
+		quote::quote! {
+			#[allow(deprecated)]
+			impl #api_generics BinaryBody for #api_name #api_generics {}
+		}
+	});
+	let api_binary_response_impl = api_binary_response.then(|| {
+		quote::quote! {
+			#[allow(deprecated)]
+			impl #api_generics BinaryResponse for #api_name #api_generics {}
+		}
+	});
+	let api_boolean_response_impl = api_boolean_response.then(|| {
+		quote::quote! {
+			#[allow(deprecated)]
+			impl #api_generics BooleanResponse for #api_name #api_generics {}
+		}
+	});
+	let api_into_owned = api_generics.lifetimes().next().map(|_| {
+		quote::quote! {
+			#[doc = concat!(
+				"Convert the [`",
+				stringify!(#api_name),
+				"`] into an owned instance which doesn't borrow any data."
+			)]
+			#api_vis fn into_owned(self) -> #api_name<'static> {
+				#api_name {
+					#(#api_ess_params: IntoStatic::into_static(self.#api_ess_params),)*
+					#(#api_opt_params: IntoStatic::into_static(self.#api_opt_params),)*
+				}
+			}
+		}
+	});
 
 	quote::quote! {
+		#(#api_doc_attrs)*
+		#api_doc_separator
 		/// GitHub reference(s):
 		#[doc = #api_doc]
+		#api_preview_doc
+		#api_deprecated_attr
 		#[derive(Debug, Clone, PartialEq, Eq)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 		#api_vis struct #api_name #api_generics {
 			#(
 				#[allow(missing_docs)]
-				#api_vis #api_path_params: #api_path_params_tys,
-			)*
-			#(
-				#[allow(missing_docs)]
-				#api_vis #api_payload_ess_params: #api_payload_ess_params_tys,
+				#api_vis #api_ess_params: #api_ess_params_tys,
 			)*
 			#(
 				#[allow(missing_docs)]
-				#api_vis #api_payload_opt_params: Option<#api_payload_opt_params_tys>,
+				#[cfg_attr(
+					feature = "serde",
+					serde(default, skip_serializing_if = "Option::is_none")
+				)]
+				#api_vis #api_opt_params: Option<#api_opt_params_tys>,
 			)*
 		}
+		#[allow(deprecated)]
 		impl #api_generics #api_name #api_generics {
 			#[doc = concat!(
 				"Build a [`",
 				stringify!(#api_name),
 				"`] instance."
 			)]
-			#api_vis fn new(
-				#(#api_path_params: #api_path_params_tys,)*
-				#(#api_payload_ess_params: #api_payload_ess_params_tys,)*
-			) -> Self {
+			#api_vis fn new(#(#api_ess_params: #api_ess_params_args,)*) -> Self {
 				Self {
-					#(#api_path_params,)*
-					#(#api_payload_ess_params,)*
-					#(#api_payload_opt_params: None,)*
+					#(#api_ess_params_inits,)*
+					#(#api_opt_params: None,)*
 				}
 			}
 
 			#(
 				#[doc = concat!(
 					"Set a new [`",
-					stringify!(#api_path_params),
+					stringify!(#api_ess_params),
 					"`](",
 					stringify!(#api_name),
 					"#structfield.",
-					stringify!(#api_path_params),
+					stringify!(#api_ess_params),
 					")."
 				)]
-				#api_vis fn #api_path_params(
+				#api_vis fn #api_ess_params(
 					mut self,
-					#api_path_params: #api_path_params_tys
+					#api_ess_params: #api_ess_params_args
 				) -> Self {
-					self.#api_path_params = #api_path_params;
+					self.#api_ess_params = #api_ess_params_values;
 
 					self
 				}
@@ -268,90 +578,297 @@ pub fn api(_: TokenStream, input: TokenStream) -> TokenStream {
 			#(
 				#[doc = concat!(
 					"Set a new [`",
-					stringify!(#api_payload_ess_params),
+					stringify!(#api_opt_params),
 					"`](",
 					stringify!(#api_name),
 					"#structfield.",
-					stringify!(#api_payload_ess_params),
+					stringify!(#api_opt_params),
 					")."
 				)]
-				#api_vis fn #api_payload_ess_params(
+				#api_vis fn #api_opt_params(
 					mut self,
-					#api_payload_ess_params: #api_payload_ess_params_tys
+					#api_opt_params: #api_opt_params_args
 				) -> Self {
-					self.#api_payload_ess_params = #api_payload_ess_params;
+					self.#api_opt_params = Some(#api_opt_params_values);
 
 					self
 				}
 			)*
-			#(
-				#[doc = concat!(
-					"Set a new [`",
-					stringify!(#api_payload_opt_params),
-					"`](",
-					stringify!(#api_name),
-					"#structfield.",
-					stringify!(#api_payload_opt_params),
-					")."
-				)]
-				#api_vis fn #api_payload_opt_params(
-					mut self,
-					#api_payload_opt_params: #api_payload_opt_params_tys
-				) -> Self {
-					self.#api_payload_opt_params = Some(#api_payload_opt_params);
 
-					self
-				}
-			)*
+			#api_into_owned
 		}
+		#[allow(deprecated)]
 		impl #api_generics Api for #api_name #api_generics {
+			#api_base_uri
 			const ACCEPT: &'static str = #api_accept;
 
 			fn api(&self) -> String {
 				format!(
 					#api_uri,
 					Self::BASE_URI,
-					#(self.#api_path_params,)*
+					#(encode_path_param(&self.#api_path_params, #api_path_params_multi_segment),)*
 				)
 			}
 		}
+		#[allow(deprecated)]
 		impl #api_generics ApiExt for #api_name #api_generics {
+			const METADATA: EndpointMetadata = EndpointMetadata {
+				name: stringify!(#api_name),
+				category: #api_category,
+				method: Method::#api_method,
+				uri: #api_uri_template,
+				accept: #api_accept,
+				path_params: &[#(#api_path_params_metadata,)*],
+				required_params: &[#(#api_payload_ess_params_metadata,)*],
+				optional_params: &[#(#api_payload_opt_params_metadata,)*],
+				header_params: &[#(#api_header_params_metadata,)*],
+				docs: #api_docs,
+				deprecated: #api_deprecated_metadata,
+				preview: #api_preview_metadata,
+				base_uri: <Self as Api>::BASE_URI,
+				binary_body: #api_binary_body,
+				binary_response: #api_binary_response,
+				boolean_response: #api_boolean_response,
+			};
 			const METHOD: Method = Method::#api_method;
 
-			fn payload_params(&self) -> Vec<(&'static str, String)> {
+			fn payload_params(&self) -> Vec<(&'static str, Param)> {
 				let mut payload_params = vec![
 					#((
 						#api_payload_ess_params_names,
-						self.#api_payload_ess_params.to_string()
+						self.#api_payload_ess_params.to_param()
 					),)*
 				];
 
 				#(
-					if let Some(#api_payload_opt_params) = self.#api_payload_opt_params {
+					if let Some(#api_payload_opt_params) = &self.#api_payload_opt_params {
 						payload_params.push((
 							#api_payload_opt_params_names,
-							#api_payload_opt_params.to_string()
+							#api_payload_opt_params.to_param()
 						));
 					}
 				)*
 
 				payload_params
 			}
+
+			fn headers(&self) -> Vec<(&'static str, String)> {
+				let mut headers = vec![
+					#((
+						#api_header_ess_params_names,
+						self.#api_header_ess_params.to_param().to_string()
+					),)*
+				];
+
+				#(
+					if let Some(#api_header_opt_params) = &self.#api_header_opt_params {
+						headers.push((
+							#api_header_opt_params_names,
+							#api_header_opt_params.to_param().to_string()
+						));
+					}
+				)*
+
+				headers
+			}
+
+			fn validate(&self) -> Result<(), Error> {
+				#(#api_validations)*
+
+				Ok(())
+			}
+		}
+		#api_binary_body_impl
+		#api_binary_response_impl
+		#api_boolean_response_impl
+		#[cfg(feature = "client")]
+		#[allow(deprecated)]
+		impl<'c, #api_generics_params> crate::client::Request<'c, #api_name #api_generics> {
+			#(
+				#[doc = concat!(
+					"Set a new [`",
+					stringify!(#api_ess_params),
+					"`](",
+					stringify!(#api_name),
+					"#structfield.",
+					stringify!(#api_ess_params),
+					")."
+				)]
+				#api_vis fn #api_ess_params(self, #api_ess_params: #api_ess_params_args) -> Self {
+					self.map(|request| request.#api_ess_params(#api_ess_params))
+				}
+			)*
+			#(
+				#[doc = concat!(
+					"Set a new [`",
+					stringify!(#api_opt_params),
+					"`](",
+					stringify!(#api_name),
+					"#structfield.",
+					stringify!(#api_opt_params),
+					")."
+				)]
+				#api_vis fn #api_opt_params(self, #api_opt_params: #api_opt_params_args) -> Self {
+					self.map(|request| request.#api_opt_params(#api_opt_params))
+				}
+			)*
 		}
 		#[doc = concat!(
 			"Build a [`",
 			stringify!(#api_name),
 			"`] instance."
 		)]
+		#api_deprecated_attr
+		#[allow(deprecated)]
 		#api_vis fn #api_name_snake_case #api_generics(
-			#(#api_path_params: #api_path_params_tys,)*
-			#(#api_payload_ess_params: #api_payload_ess_params_tys,)*
+			#(#api_ess_params: #api_ess_params_args,)*
 		) -> #api_name #api_generics {
-			#api_name::new(
-				#(#api_path_params,)*
-				#(#api_payload_ess_params,)*
-			)
+			#api_name::new(#(#api_ess_params,)*)
 		}
 	}
 	.into()
 }
+
+/// Generate a GitHub REST API parameter from a unit-only enum.
+///
+/// Each variant is serialized as its snake case name unless it is overridden by
+/// `#[rename = "..."]`.
+///
+/// # Example
+/// ```ignore
+/// use githuber::prelude::*;
+///
+/// /// Reason for locking an issue.
+/// #[api_impl::param]
+/// pub enum LockReason {
+/// 	#[rename = "off-topic"]
+/// 	OffTopic,
+/// 	#[rename = "too heated"]
+/// 	TooHeated,
+/// 	Resolved,
+/// 	Spam,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn param(_: TokenStream, input: TokenStream) -> TokenStream {
+	let param_enum = syn::parse_macro_input!(input as ItemEnum);
+	let param_attrs = param_enum.attrs;
+	let param_vis = param_enum.vis;
+	let param_name = param_enum.ident;
+	let mut param_variants = Vec::new();
+	let mut param_variants_values = Vec::new();
+
+	param_enum.variants.into_iter().for_each(|variant| {
+		let Fields::Unit = variant.fields else {
+			panic!("expect a `Fields::Unit` here");
+		};
+		let value = variant
+			.attrs
+			.iter()
+			.find(|attr| attr.path().is_ident("rename"))
+			.map(rename_value)
+			.unwrap_or_else(|| variant.ident.to_string().to_case(Case::Snake));
+
+		param_variants.push(variant.ident);
+		param_variants_values.push(value);
+	});
+
+	quote::quote! {
+		#(#param_attrs)*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+		#param_vis enum #param_name {
+			#(
+				#[doc = concat!("`", #param_variants_values, "`")]
+				#[cfg_attr(feature = "serde", serde(rename = #param_variants_values))]
+				#param_variants,
+			)*
+		}
+		impl #param_name {
+			#[doc = concat!(
+				"Get the [`",
+				stringify!(#param_name),
+				"`]'s string representation."
+			)]
+			#param_vis const fn as_str(&self) -> &'static str {
+				match self {
+					#(Self::#param_variants => #param_variants_values,)*
+				}
+			}
+		}
+		impl ToParam for #param_name {
+			fn to_param(&self) -> Param {
+				Param::String(self.as_str().into())
+			}
+		}
+		impl IntoStatic for #param_name {
+			type Static = Self;
+
+			fn into_static(self) -> Self::Static {
+				self
+			}
+		}
+	}
+	.into()
+}
+
+fn cow_str(ty: Type) -> Type {
+	if !is_str_ref(&ty) {
+		return ty;
+	}
+
+	let Type::Reference(TypeReference { lifetime, .. }) = ty else { unreachable!() };
+
+	syn::parse_quote!(::std::borrow::Cow<#lifetime, str>)
+}
+
+fn is_str_ref(ty: &Type) -> bool {
+	matches!(
+		ty,
+		Type::Reference(TypeReference { lifetime: Some(_), elem, .. })
+			if matches!(&**elem, Type::Path(path) if path.path.is_ident("str"))
+	)
+}
+
+fn option_inner(ty: &Type) -> Option<Type> {
+	let Type::Path(path) = ty else { return None };
+	let segment = &path.path.segments[0];
+
+	if segment.ident != "Option" {
+		return None;
+	}
+
+	let PathArguments::AngleBracketed(args) = &segment.arguments else {
+		panic!("expect a `PathArguments::AngleBracketed` here");
+	};
+	let GenericArgument::Type(ty) = &args.args[0] else {
+		panic!("expect a `GenericArgument::Type` here");
+	};
+
+	Some(ty.to_owned())
+}
+
+fn param_metadata(name: &str, ty: &Type) -> impl quote::ToTokens {
+	let ty = quote::quote!(#ty)
+		.to_string()
+		.replace("& '", "&'")
+		.replace(" < ", "<")
+		.replace(" >", ">")
+		.replace(" ,", ",");
+
+	quote::quote! {
+		ParamMetadata { name: #name, ty: #ty }
+	}
+}
+
+fn rename_value(attr: &Attribute) -> String {
+	let Meta::NameValue(MetaNameValue {
+		value: Expr::Lit(ExprLit { lit: Lit::Str(value), .. }),
+		..
+	}) = &attr.meta
+	else {
+		panic!("expect a `#[rename = \"...\"]` here");
+	};
+
+	value.value()
+}
@@ -2,6 +2,12 @@
 //!
 //! GitHub reference(s):
 //! - <https://docs.github.com/en/rest/issues/issues>
+//! - <https://docs.github.com/en/rest/issues/assignees>
+//! - <https://docs.github.com/en/rest/issues/comments>
+//! - <https://docs.github.com/en/rest/issues/events>
+//! - <https://docs.github.com/en/rest/issues/labels>
+//! - <https://docs.github.com/en/rest/issues/milestones>
+//! - <https://docs.github.com/en/rest/issues/timeline>
 
 #[cfg(test)] mod test;
 
@@ -16,16 +22,19 @@ use crate::prelude::*;
 	uri = "/issues"
 )]
 pub struct ListIssuesAssignedToTheAuthenticatedUser<'a> {
-	pub filter: Option<&'a str>,
-	pub state: Option<&'a str>,
-	pub labels: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
-	pub since: Option<&'a str>,
+	pub filter: Option<IssueFilter>,
+	pub state: Option<State>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	pub sort: Option<IssueSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
 	pub collab: Option<bool>,
 	pub orgs: Option<bool>,
 	pub owned: Option<bool>,
 	pub pulls: Option<bool>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -40,16 +49,56 @@ pub struct ListIssuesAssignedToTheAuthenticatedUser<'a> {
 pub struct ListOrganizationIssuesAssignedToTheAuthenticatedUser<'a> {
 	#[path_param]
 	pub org: &'a str,
-	pub filter: Option<&'a str>,
-	pub state: Option<&'a str>,
-	pub labels: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
-	pub since: Option<&'a str>,
+	pub filter: Option<IssueFilter>,
+	pub state: Option<State>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	pub sort: Option<IssueSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
 
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/assignees",
+	docs = "https://docs.github.com/en/rest/issues/assignees#list-assignees"
+)]
+pub struct ListAssignees<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+/// GitHub responds `204` if the user can be assigned, and `404` otherwise.
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/assignees/{}",
+	docs = "https://docs.github.com/en/rest/issues/assignees#check-if-a-user-can-be-assigned",
+	response = "boolean"
+)]
+pub struct CheckIfAUserCanBeAssigned<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub assignee: &'a str,
+}
+
 #[api_impl::api]
 #[properties(
 	category = "issues",
@@ -63,14 +112,17 @@ pub struct ListRepositoryIssues<'a> {
 	#[path_param]
 	pub repo: &'a str,
 	pub milestone: Option<&'a str>,
-	pub state: Option<&'a str>,
+	pub state: Option<State>,
 	pub assignee: Option<&'a str>,
 	pub creator: Option<&'a str>,
 	pub mentioned: Option<&'a str>,
-	pub labels: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
-	pub since: Option<&'a str>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	pub sort: Option<IssueSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -90,12 +142,128 @@ pub struct CreateAnIssue<'a> {
 	#[payload_ess_param]
 	pub title: &'a str,
 	pub body: Option<&'a str>,
-	// TODO: JSON?
-	pub milestone: Option<&'a str>,
-	// TODO: JSON?
-	pub labels: Option<&'a str>,
-	// TODO: JSON?
-	pub assignees: Option<&'a str>,
+	pub assignee: Option<&'a str>,
+	pub milestone: Option<u32>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	#[into]
+	pub assignees: Option<List<'a>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments",
+	docs = "https://docs.github.com/en/rest/issues/comments#list-issue-comments-for-a-repository"
+)]
+pub struct ListIssueCommentsForARepository<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	pub sort: Option<IssueCommentSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+	#[header_param("Accept")]
+	pub media_type: Option<MediaType>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments/{}",
+	docs = "https://docs.github.com/en/rest/issues/comments#get-an-issue-comment"
+)]
+pub struct GetAnIssueComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[header_param("Accept")]
+	pub media_type: Option<MediaType>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments/{}",
+	docs = "https://docs.github.com/en/rest/issues/comments#update-an-issue-comment"
+)]
+pub struct UpdateAnIssueComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[payload_ess_param]
+	pub body: &'a str,
+	#[header_param("Accept")]
+	pub media_type: Option<MediaType>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments/{}",
+	docs = "https://docs.github.com/en/rest/issues/comments#delete-an-issue-comment"
+)]
+pub struct DeleteAnIssueComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/events",
+	docs = "https://docs.github.com/en/rest/issues/events#list-issue-events-for-a-repository"
+)]
+pub struct ListIssueEventsForARepository<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/events/{}",
+	docs = "https://docs.github.com/en/rest/issues/events#get-an-issue-event"
+)]
+pub struct GetAnIssueEvent<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub event_id: u64,
 }
 
 #[api_impl::api]
@@ -114,6 +282,7 @@ pub struct GetAnIssue<'a> {
 	pub issue_number: u32,
 }
 
+/// `milestone(None)` removes the issue's milestone.
 #[api_impl::api]
 #[properties(
 	category = "issues",
@@ -130,14 +299,236 @@ pub struct UpdateAnIssue<'a> {
 	pub issue_number: u32,
 	pub title: Option<&'a str>,
 	pub body: Option<&'a str>,
-	pub state: Option<&'a str>,
-	pub state_reason: Option<&'a str>,
-	// TODO: JSON
-	pub milestone: Option<&'a str>,
-	// TODO: JSON
-	pub labels: Option<&'a str>,
-	// TODO: JSON
-	pub assignees: Option<&'a str>,
+	pub state: Option<IssueState>,
+	pub state_reason: Option<StateReason>,
+	pub assignee: Option<&'a str>,
+	pub milestone: Option<Option<u32>>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	#[into]
+	pub assignees: Option<List<'a>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/assignees",
+	docs = "https://docs.github.com/en/rest/issues/assignees#add-assignees-to-an-issue"
+)]
+pub struct AddAssigneesToAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[payload_ess_param]
+	#[into]
+	pub assignees: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/assignees",
+	docs = "https://docs.github.com/en/rest/issues/assignees#remove-assignees-from-an-issue"
+)]
+pub struct RemoveAssigneesFromAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[payload_ess_param]
+	#[into]
+	pub assignees: List<'a>,
+}
+
+/// GitHub responds `204` if the user can be assigned to the issue, and `404` otherwise.
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/assignees/{}",
+	docs = "https://docs.github.com/en/rest/issues/assignees#check-if-a-user-can-be-assigned-to-a-issue",
+	response = "boolean"
+)]
+pub struct CheckIfAUserCanBeAssignedToAIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[path_param]
+	pub assignee: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/comments",
+	docs = "https://docs.github.com/en/rest/issues/comments#list-issue-comments"
+)]
+pub struct ListIssueComments<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+	#[header_param("Accept")]
+	pub media_type: Option<MediaType>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/comments",
+	docs = "https://docs.github.com/en/rest/issues/comments#create-an-issue-comment"
+)]
+pub struct CreateAnIssueComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[payload_ess_param]
+	pub body: &'a str,
+	#[header_param("Accept")]
+	pub media_type: Option<MediaType>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/events",
+	docs = "https://docs.github.com/en/rest/issues/events#list-issue-events"
+)]
+pub struct ListIssueEvents<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#list-labels-for-an-issue"
+)]
+pub struct ListLabelsForAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#add-labels-to-an-issue"
+)]
+pub struct AddLabelsToAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[payload_ess_param]
+	#[into]
+	pub labels: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#set-labels-for-an-issue"
+)]
+pub struct SetLabelsForAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[payload_ess_param]
+	#[into]
+	pub labels: List<'a>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#remove-all-labels-from-an-issue"
+)]
+pub struct RemoveAllLabelsFromAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/labels/{}",
+	docs = "https://docs.github.com/en/rest/issues/labels#remove-a-label-from-an-issue"
+)]
+pub struct RemoveALabelFromAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[path_param]
+	pub name: &'a str,
 }
 
 #[api_impl::api]
@@ -154,7 +545,7 @@ pub struct LockAnIssue<'a> {
 	pub repo: &'a str,
 	#[path_param]
 	pub issue_number: u32,
-	pub lock_reason: Option<&'a str>,
+	pub lock_reason: Option<LockReason>,
 }
 
 #[api_impl::api]
@@ -173,6 +564,235 @@ pub struct UnlockAnIssue<'a> {
 	pub issue_number: u32,
 }
 
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/timeline",
+	docs = "https://docs.github.com/en/rest/issues/timeline#list-timeline-events-for-an-issue"
+)]
+pub struct ListTimelineEventsForAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#list-labels-for-a-repository"
+)]
+pub struct ListLabelsForARepository<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#create-a-label"
+)]
+pub struct CreateALabel<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub name: &'a str,
+	pub color: Option<&'a str>,
+	pub description: Option<&'a str>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/labels/{}",
+	docs = "https://docs.github.com/en/rest/issues/labels#get-a-label"
+)]
+pub struct GetALabel<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub name: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/labels/{}",
+	docs = "https://docs.github.com/en/rest/issues/labels#update-a-label"
+)]
+pub struct UpdateALabel<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub name: &'a str,
+	pub new_name: Option<&'a str>,
+	pub color: Option<&'a str>,
+	pub description: Option<&'a str>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/labels/{}",
+	docs = "https://docs.github.com/en/rest/issues/labels#delete-a-label"
+)]
+pub struct DeleteALabel<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub name: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/milestones",
+	docs = "https://docs.github.com/en/rest/issues/milestones#list-milestones"
+)]
+pub struct ListMilestones<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	pub state: Option<State>,
+	pub sort: Option<MilestoneSort>,
+	pub direction: Option<Direction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/milestones",
+	docs = "https://docs.github.com/en/rest/issues/milestones#create-a-milestone"
+)]
+pub struct CreateAMilestone<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub title: &'a str,
+	pub state: Option<IssueState>,
+	pub description: Option<&'a str>,
+	#[into]
+	pub due_on: Option<Timestamp>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/milestones/{}",
+	docs = "https://docs.github.com/en/rest/issues/milestones#get-a-milestone"
+)]
+pub struct GetAMilestone<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub milestone_number: u32,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/milestones/{}",
+	docs = "https://docs.github.com/en/rest/issues/milestones#update-a-milestone"
+)]
+pub struct UpdateAMilestone<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub milestone_number: u32,
+	pub title: Option<&'a str>,
+	pub state: Option<IssueState>,
+	pub description: Option<&'a str>,
+	#[into]
+	pub due_on: Option<Timestamp>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/milestones/{}",
+	docs = "https://docs.github.com/en/rest/issues/milestones#delete-a-milestone"
+)]
+pub struct DeleteAMilestone<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub milestone_number: u32,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/milestones/{}/labels",
+	docs = "https://docs.github.com/en/rest/issues/labels#list-labels-for-issues-in-a-milestone"
+)]
+pub struct ListLabelsForIssuesInAMilestone<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub milestone_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
 #[api_impl::api]
 #[properties(
 	category = "issues",
@@ -181,12 +801,245 @@ pub struct UnlockAnIssue<'a> {
 	uri = "/user/issues"
 )]
 pub struct ListUserAccountIssuesAssignedToTheAuthenticatedUser<'a> {
-	pub filter: Option<&'a str>,
-	pub state: Option<&'a str>,
-	pub labels: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
-	pub since: Option<&'a str>,
+	pub filter: Option<IssueFilter>,
+	pub state: Option<State>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	pub sort: Option<IssueSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
+
+/// Which issues to return.
+#[api_impl::param]
+pub enum IssueFilter {
+	Assigned,
+	Created,
+	Mentioned,
+	Subscribed,
+	Repos,
+	All,
+}
+
+/// State of an issue or a milestone.
+#[api_impl::param]
+pub enum IssueState {
+	Open,
+	Closed,
+}
+
+/// What to sort issues by.
+#[api_impl::param]
+pub enum IssueSort {
+	Created,
+	Updated,
+	Comments,
+}
+
+/// What to sort issue comments by.
+#[api_impl::param]
+pub enum IssueCommentSort {
+	Created,
+	Updated,
+}
+
+/// What to sort milestones by.
+#[api_impl::param]
+pub enum MilestoneSort {
+	DueOn,
+	Completeness,
+}
+
+/// Reason for the state change of an issue.
+#[api_impl::param]
+pub enum StateReason {
+	Completed,
+	NotPlanned,
+	Reopened,
+}
+
+/// Reason for locking an issue or a pull request conversation.
+#[api_impl::param]
+pub enum LockReason {
+	#[rename = "off-topic"]
+	OffTopic,
+	#[rename = "too heated"]
+	TooHeated,
+	Resolved,
+	Spam,
+}
+
+/// An issue event, or an item of an issue's timeline.
+///
+/// Only the fields which tell who did what and when are modeled, the others are ignored.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEvent {
+	/// Absent from some timeline items, e.g. `committed`.
+	pub id: Option<u64>,
+	/// What happened.
+	pub event: IssueEventKind,
+	/// Who did it, `user` or `author` in some timeline items instead.
+	pub actor: Option<IssueEventUser>,
+	/// When it happened, `submitted_at` in the `reviewed` timeline items instead.
+	pub created_at: Option<Timestamp>,
+	/// Commit which closed or referenced the issue.
+	pub commit_id: Option<String>,
+	/// Label of the `labeled` and `unlabeled` events.
+	pub label: Option<IssueEventLabel>,
+	/// Assignee of the `assigned` and `unassigned` events.
+	pub assignee: Option<IssueEventUser>,
+	/// Milestone of the `milestoned` and `demilestoned` events.
+	pub milestone: Option<IssueEventMilestone>,
+	/// Titles of the `renamed` events.
+	pub rename: Option<IssueEventRename>,
+	/// Where the issue is referenced from, for the `cross-referenced` timeline items.
+	pub source: Option<IssueEventSource>,
+}
+
+/// Kind of an [`IssueEvent`].
+///
+/// See <https://docs.github.com/en/webhooks-and-events/events/issue-event-types>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum IssueEventKind {
+	#[allow(missing_docs)]
+	AddedToProject,
+	#[allow(missing_docs)]
+	Assigned,
+	#[allow(missing_docs)]
+	Closed,
+	/// Timeline only.
+	Commented,
+	/// Timeline only.
+	Committed,
+	#[allow(missing_docs)]
+	ConvertToDraft,
+	/// Timeline only.
+	#[cfg_attr(feature = "serde", serde(rename = "cross-referenced"))]
+	CrossReferenced,
+	#[allow(missing_docs)]
+	Demilestoned,
+	#[allow(missing_docs)]
+	HeadRefDeleted,
+	#[allow(missing_docs)]
+	HeadRefForcePushed,
+	#[allow(missing_docs)]
+	HeadRefRestored,
+	#[allow(missing_docs)]
+	Labeled,
+	#[allow(missing_docs)]
+	Locked,
+	#[allow(missing_docs)]
+	MarkedAsDuplicate,
+	#[allow(missing_docs)]
+	Mentioned,
+	#[allow(missing_docs)]
+	Merged,
+	#[allow(missing_docs)]
+	Milestoned,
+	#[allow(missing_docs)]
+	Pinned,
+	#[allow(missing_docs)]
+	ReadyForReview,
+	#[allow(missing_docs)]
+	Referenced,
+	#[allow(missing_docs)]
+	RemovedFromProject,
+	#[allow(missing_docs)]
+	Renamed,
+	#[allow(missing_docs)]
+	Reopened,
+	#[allow(missing_docs)]
+	ReviewDismissed,
+	#[allow(missing_docs)]
+	ReviewRequested,
+	#[allow(missing_docs)]
+	ReviewRequestRemoved,
+	/// Timeline only.
+	Reviewed,
+	#[allow(missing_docs)]
+	Subscribed,
+	#[allow(missing_docs)]
+	Transferred,
+	#[allow(missing_docs)]
+	Unassigned,
+	#[allow(missing_docs)]
+	Unlabeled,
+	#[allow(missing_docs)]
+	Unlocked,
+	#[allow(missing_docs)]
+	UnmarkedAsDuplicate,
+	#[allow(missing_docs)]
+	Unpinned,
+	#[allow(missing_docs)]
+	Unsubscribed,
+	/// An event which isn't known by the crate yet.
+	#[cfg_attr(feature = "serde", serde(other))]
+	Other,
+}
+
+/// User of an [`IssueEvent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEventUser {
+	#[allow(missing_docs)]
+	pub login: String,
+	#[allow(missing_docs)]
+	pub id: u64,
+}
+
+/// `label` of [`IssueEvent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEventLabel {
+	#[allow(missing_docs)]
+	pub name: String,
+	#[allow(missing_docs)]
+	pub color: String,
+}
+
+/// `milestone` of [`IssueEvent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEventMilestone {
+	#[allow(missing_docs)]
+	pub title: String,
+}
+
+/// `rename` of [`IssueEvent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEventRename {
+	#[allow(missing_docs)]
+	pub from: String,
+	#[allow(missing_docs)]
+	pub to: String,
+}
+
+/// `source` of [`IssueEvent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEventSource {
+	#[allow(missing_docs)]
+	pub r#type: String,
+	/// The referencing issue or pull request.
+	pub issue: Option<IssueEventSourceIssue>,
+}
+
+/// `issue` of [`IssueEventSource`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueEventSourceIssue {
+	#[allow(missing_docs)]
+	pub number: u32,
+	#[allow(missing_docs)]
+	pub title: String,
+	#[allow(missing_docs)]
+	pub html_url: String,
+}
@@ -5,3 +5,237 @@ use crate::test_util::*;
 async fn list_repository_issues_should_work() {
 	api_client().unwrap().get(list_repository_issues("hack-ink", "githuber")).await.unwrap();
 }
+
+#[test]
+fn update_an_issue_payload_params_should_work() {
+	assert_eq!(
+		update_an_issue("hack-ink", "githuber", 1)
+			.state(IssueState::Closed)
+			.state_reason(StateReason::NotPlanned)
+			.payload_params(),
+		vec![
+			("state", Param::String("closed".into())),
+			("state_reason", Param::String("not_planned".into()))
+		]
+	);
+}
+
+#[test]
+fn path_params_should_be_encoded() {
+	assert_eq!(
+		get_a_label("hack-ink", "githuber", "kind/bug").api(),
+		"https://api.github.com/repos/hack-ink/githuber/labels/kind%2Fbug"
+	);
+	assert_eq!(
+		delete_a_label("hack-ink", "githuber", "C# why?").api(),
+		"https://api.github.com/repos/hack-ink/githuber/labels/C%23%20why%3F"
+	);
+}
+
+#[test]
+fn list_params_should_work() {
+	let labels = ["bug", "ui"];
+	let request = list_repository_issues("hack-ink", "githuber").labels(labels.as_slice());
+
+	assert_eq!(query_pairs(request.payload_params()), vec![("labels", "bug,ui".into())]);
+	assert_eq!(
+		query_pairs(
+			list_repository_issues("hack-ink", "githuber").labels("bug,ui").payload_params()
+		),
+		vec![("labels", "bug,ui".into())]
+	);
+	assert_eq!(
+		create_an_issue("hack-ink", "githuber", "title").labels("kind/bug, ui").payload_params(),
+		vec![
+			("title", Param::String("title".into())),
+			("labels", Param::Array(vec![Param::String("kind/bug, ui".into())]))
+		]
+	);
+	assert_eq!(
+		create_an_issue("hack-ink", "githuber", "title")
+			.labels(vec![String::from("bug")])
+			.payload_params(),
+		vec![
+			("title", Param::String("title".into())),
+			("labels", Param::Array(vec![Param::String("bug".into())]))
+		]
+	);
+
+	#[api_impl::api]
+	#[properties(
+		category = "issues",
+		method = "GET",
+		accept = "application/vnd.github+json",
+		uri = "/repos/{}/{}/issues"
+	)]
+	struct ListRepositoryIssuesRepeated<'a> {
+		#[path_param]
+		owner: &'a str,
+		#[path_param]
+		repo: &'a str,
+		#[repeated]
+		#[into]
+		labels: Option<List<'a>>,
+	}
+
+	assert_eq!(
+		query_pairs(
+			list_repository_issues_repeated("hack-ink", "githuber").labels(labels).payload_params()
+		),
+		vec![("labels[]", "bug".into()), ("labels[]", "ui".into())]
+	);
+
+	#[cfg(feature = "serde")]
+	for list in [List::from("kind/bug, ui"), List::from(labels)] {
+		let json = serde_json::to_string(&list).unwrap();
+
+		assert_eq!(serde_json::from_str::<List>(&json).unwrap(), list);
+	}
+	#[cfg(feature = "serde")]
+	assert_eq!(
+		serde_json::from_str::<List>(r#""kind/bug, ui""#).unwrap(),
+		List::from("kind/bug, ui")
+	);
+}
+
+#[test]
+fn issue_comments_should_work() {
+	let request = create_an_issue_comment("hack-ink", "githuber", 1, "Thanks!");
+
+	assert_eq!(request.api(), "https://api.github.com/repos/hack-ink/githuber/issues/1/comments");
+	assert_eq!(request.payload_params(), vec![("body", Param::String("Thanks!".into()))]);
+	assert!(request.headers().is_empty());
+	assert_eq!(
+		request.media_type(MediaType::Html).headers(),
+		vec![("Accept", "application/vnd.github.html+json".into())]
+	);
+	assert_eq!(
+		query_pairs(
+			list_issue_comments_for_a_repository("hack-ink", "githuber")
+				.sort(IssueCommentSort::Updated)
+				.since("2024-01-01T00:00:00Z")
+				.payload_params()
+		),
+		vec![("sort", "updated".into()), ("since", "2024-01-01T00:00:00Z".into())]
+	);
+}
+
+#[test]
+fn labels_should_work() {
+	assert_eq!(
+		add_labels_to_an_issue("hack-ink", "githuber", 1, ["bug", "ui"]).payload_params(),
+		vec![(
+			"labels",
+			Param::Array(vec![Param::String("bug".into()), Param::String("ui".into())])
+		)]
+	);
+	assert_eq!(
+		remove_a_label_from_an_issue("hack-ink", "githuber", 1, "bug").api(),
+		"https://api.github.com/repos/hack-ink/githuber/issues/1/labels/bug"
+	);
+	assert_eq!(
+		update_a_label("hack-ink", "githuber", "bug").new_name("defect").payload_params(),
+		vec![("new_name", Param::String("defect".into()))]
+	);
+}
+
+#[test]
+fn milestones_should_work() {
+	assert_eq!(
+		create_a_milestone("hack-ink", "githuber", "v0.5.0")
+			.due_on("2024-12-31T00:00:00Z")
+			.payload_params(),
+		vec![
+			("title", Param::String("v0.5.0".into())),
+			("due_on", Param::String("2024-12-31T00:00:00Z".into()))
+		]
+	);
+	assert_eq!(
+		list_milestones("hack-ink", "githuber").sort(MilestoneSort::DueOn).payload_params(),
+		vec![("sort", Param::String("due_on".into()))]
+	);
+	assert_eq!(
+		delete_a_milestone("hack-ink", "githuber", 1).api(),
+		"https://api.github.com/repos/hack-ink/githuber/milestones/1"
+	);
+	assert_eq!(
+		create_an_issue("hack-ink", "githuber", "title").milestone(1).payload_params(),
+		vec![("title", Param::String("title".into())), ("milestone", Param::Integer(1))]
+	);
+	assert_eq!(
+		update_an_issue("hack-ink", "githuber", 1).milestone(None).payload_params(),
+		vec![("milestone", Param::Null)]
+	);
+}
+
+#[test]
+fn assignees_should_work() {
+	const { assert!(CheckIfAUserCanBeAssigned::METADATA.boolean_response) };
+
+	assert_eq!(
+		check_if_a_user_can_be_assigned_to_a_issue("hack-ink", "githuber", 1, "aurexav").api(),
+		"https://api.github.com/repos/hack-ink/githuber/issues/1/assignees/aurexav"
+	);
+	assert_eq!(
+		remove_assignees_from_an_issue("hack-ink", "githuber", 1, ["aurexav"]).payload_params(),
+		vec![("assignees", Param::Array(vec![Param::String("aurexav".into())]))]
+	);
+}
+
+#[test]
+fn events_should_work() {
+	assert_eq!(
+		get_an_issue_event("hack-ink", "githuber", 14_000_000_000).api(),
+		"https://api.github.com/repos/hack-ink/githuber/issues/events/14000000000"
+	);
+	assert_eq!(
+		list_timeline_events_for_an_issue("hack-ink", "githuber", 1).api(),
+		"https://api.github.com/repos/hack-ink/githuber/issues/1/timeline"
+	);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn issue_event_should_deserialize() {
+	let events = serde_json::from_value::<Vec<IssueEvent>>(serde_json::json!([
+		{
+			"id": 1,
+			"event": "labeled",
+			"actor": { "login": "aurexav", "id": 2 },
+			"created_at": "2024-01-01T00:00:00Z",
+			"label": { "name": "bug", "color": "d73a4a" }
+		},
+		{
+			"event": "cross-referenced",
+			"source": {
+				"type": "issue",
+				"issue": { "number": 2, "title": "Fix", "html_url": "https://github.com" }
+			}
+		},
+		{ "sha": "0000000", "event": "committed" },
+		{ "id": 3, "event": "something_new" }
+	]))
+	.unwrap();
+
+	assert_eq!(events[0].event, IssueEventKind::Labeled);
+	assert_eq!(events[0].label.as_ref().unwrap().name, "bug");
+	assert_eq!(events[0].created_at, Some("2024-01-01T00:00:00Z".into()));
+	#[cfg(feature = "time")]
+	assert_eq!(
+		time::OffsetDateTime::try_from(events[0].created_at.as_ref().unwrap())
+			.unwrap()
+			.unix_timestamp(),
+		1_704_067_200
+	);
+	#[cfg(feature = "chrono")]
+	assert_eq!(
+		chrono::DateTime::<chrono::Utc>::try_from(events[0].created_at.as_ref().unwrap())
+			.unwrap()
+			.timestamp(),
+		1_704_067_200
+	);
+	assert_eq!(events[1].event, IssueEventKind::CrossReferenced);
+	assert_eq!(events[1].source.as_ref().unwrap().issue.as_ref().unwrap().number, 2);
+	assert_eq!(events[2].id, None);
+	assert_eq!(events[3].event, IssueEventKind::Other);
+}
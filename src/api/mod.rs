@@ -1,9 +1,21 @@
 //! GitHub REST API collections.
 
+pub mod actions;
+pub mod branches;
 pub mod commits;
+pub mod endpoint;
 pub mod issues;
+pub mod param;
+pub mod pulls;
+pub mod reactions;
+pub mod releases;
 pub mod repos;
 
+pub use endpoint::endpoints;
+
+// hack-ink
+use crate::prelude::*;
+
 /// Abstraction of GitHub REST API.
 pub trait Api {
 	/// GitHub REST APIs' base prefix.
@@ -17,6 +29,8 @@ pub trait Api {
 
 /// Extended GitHub REST API.
 pub trait ApiExt: Api {
+	/// Static description of the API.
+	const METADATA: EndpointMetadata;
 	/// HTTP method.
 	const METHOD: Method;
 
@@ -25,10 +39,41 @@ pub trait ApiExt: Api {
 	/// Can be one of:
 	/// - Body parameters
 	/// - Query parameters
-	fn payload_params(&self) -> Vec<(&'static str, String)>;
+	fn payload_params(&self) -> Vec<(&'static str, Param)>;
+
+	/// Request's specific headers.
+	///
+	/// These are applied on top of the client's default headers, and replace the ones with the
+	/// same name, e.g. `Accept`.
+	fn headers(&self) -> Vec<(&'static str, String)> {
+		Vec::new()
+	}
+
+	/// Validate the request's parameters before sending it.
+	fn validate(&self) -> Result<(), Error> {
+		Ok(())
+	}
 }
 
+/// APIs whose request body is raw binary data instead of JSON.
+///
+/// Their payload parameters are sent as the query string.
+pub trait BinaryBody: ApiExt {}
+
+/// APIs whose response body is raw binary data instead of JSON.
+///
+/// GitHub usually redirects them to the storage, the client follows the redirection.
+pub trait BinaryResponse: ApiExt {}
+
+/// APIs whose response is told by the status instead of a body.
+///
+/// GitHub responds `204` for `true` and `404` for `false`.
+pub trait BooleanResponse: ApiExt {}
+
 /// HTTP methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum Method {
 	/// HTTP DELETE method.
 	Delete,
@@ -0,0 +1,632 @@
+//! GitHub REST API parameters.
+
+// std
+use std::{
+	borrow::Cow,
+	collections::BTreeMap,
+	fmt::{Display, Formatter, Result as FmtResult},
+};
+// crates.io
+use base64::{engine::general_purpose::STANDARD, Engine};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC};
+// hack-ink
+use crate::error::Error;
+
+/// Serialized value of a request parameter.
+///
+/// It's also an arbitrary JSON value, e.g. of a dispatch event's `client_payload`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+pub enum Param {
+	/// Boolean value.
+	Bool(bool),
+	/// Integer value.
+	Integer(i64),
+	/// Unsigned integer value, e.g. an ID which could exceed `i64::MAX`.
+	UnsignedInteger(u64),
+	/// String value.
+	String(String),
+	/// Array value.
+	///
+	/// Displayed as the comma-joined items.
+	Array(Vec<Param>),
+	/// Object value, only sent in a JSON body.
+	///
+	/// Displayed as the comma-joined `key=value` pairs.
+	Object(BTreeMap<String, Param>),
+	/// `null`, for the essential parameters which could be disabled explicitly.
+	Null,
+}
+impl Param {
+	/// Build a [`Param::Object`] from the fields, [`Param::Null`]s are skipped.
+	pub fn object<I, K>(fields: I) -> Self
+	where
+		I: IntoIterator<Item = (K, Param)>,
+		K: Into<String>,
+	{
+		Self::Object(
+			fields
+				.into_iter()
+				.filter(|(_, v)| *v != Self::Null)
+				.map(|(k, v)| (k.into(), v))
+				.collect(),
+		)
+	}
+}
+impl Display for Param {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		match self {
+			Self::Bool(v) => v.fmt(f),
+			Self::Integer(v) => v.fmt(f),
+			Self::UnsignedInteger(v) => v.fmt(f),
+			Self::String(v) => v.fmt(f),
+			Self::Array(v) => {
+				for (i, v) in v.iter().enumerate() {
+					if i != 0 {
+						f.write_str(",")?;
+					}
+
+					v.fmt(f)?;
+				}
+
+				Ok(())
+			},
+			Self::Object(v) => {
+				for (i, (k, v)) in v.iter().enumerate() {
+					if i != 0 {
+						f.write_str(",")?;
+					}
+
+					write!(f, "{k}={v}")?;
+				}
+
+				Ok(())
+			},
+			Self::Null => f.write_str("null"),
+		}
+	}
+}
+
+/// Encode the payload parameters as the query string's pairs.
+///
+/// Arrays are comma-joined, unless the key ends with `[]`, in which case the key is repeated for
+/// each item.
+pub fn query_pairs(payload_params: Vec<(&'static str, Param)>) -> Vec<(&'static str, String)> {
+	payload_params
+		.into_iter()
+		.flat_map(|(k, v)| match v {
+			Param::Array(v) if k.ends_with("[]") =>
+				v.into_iter().map(|v| (k, v.to_string())).collect(),
+			v => vec![(k, v.to_string())],
+		})
+		.collect()
+}
+
+/// Characters which are percent-encoded in a path parameter, everything but the unreserved ones.
+const PATH_SEGMENT: &AsciiSet =
+	&NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+/// [`PATH_SEGMENT`] but keep the slashes.
+const PATH_SEGMENTS: &AsciiSet = &PATH_SEGMENT.remove(b'/');
+
+/// Percent-encode a path parameter, e.g. `kind/bug` to `kind%2Fbug`.
+///
+/// A multi-segment parameter, e.g. a file path, keeps its slashes.
+pub fn encode_path_param(value: impl Display, multi_segment: bool) -> String {
+	let set = if multi_segment { PATH_SEGMENTS } else { PATH_SEGMENT };
+
+	percent_encoding::utf8_percent_encode(&value.to_string(), set).to_string()
+}
+
+/// Types which could be used as a request parameter.
+pub trait ToParam {
+	/// Serialize `self` into a [`Param`].
+	fn to_param(&self) -> Param;
+}
+impl<T> ToParam for &T
+where
+	T: ?Sized + ToParam,
+{
+	fn to_param(&self) -> Param {
+		T::to_param(self)
+	}
+}
+impl<T> ToParam for Option<T>
+where
+	T: ToParam,
+{
+	fn to_param(&self) -> Param {
+		self.as_ref().map_or(Param::Null, ToParam::to_param)
+	}
+}
+impl<T> ToParam for Vec<T>
+where
+	T: ToParam,
+{
+	fn to_param(&self) -> Param {
+		Param::Array(self.iter().map(ToParam::to_param).collect())
+	}
+}
+impl<K, V> ToParam for BTreeMap<K, V>
+where
+	K: AsRef<str>,
+	V: ToParam,
+{
+	fn to_param(&self) -> Param {
+		Param::object(self.iter().map(|(k, v)| (k.as_ref(), v.to_param())))
+	}
+}
+impl ToParam for Param {
+	fn to_param(&self) -> Param {
+		self.to_owned()
+	}
+}
+impl ToParam for bool {
+	fn to_param(&self) -> Param {
+		Param::Bool(*self)
+	}
+}
+impl ToParam for str {
+	fn to_param(&self) -> Param {
+		Param::String(self.to_owned())
+	}
+}
+impl ToParam for String {
+	fn to_param(&self) -> Param {
+		Param::String(self.to_owned())
+	}
+}
+impl ToParam for Cow<'_, str> {
+	fn to_param(&self) -> Param {
+		Param::String(self.as_ref().to_owned())
+	}
+}
+macro_rules! impl_to_param_for_integers {
+	($($ty:ty),*) => {
+		$(
+			impl ToParam for $ty {
+				fn to_param(&self) -> Param {
+					Param::Integer((*self).into())
+				}
+			}
+		)*
+	};
+}
+impl_to_param_for_integers!(u8, u16, u32, i8, i16, i32, i64);
+impl ToParam for u64 {
+	fn to_param(&self) -> Param {
+		Param::UnsignedInteger(*self)
+	}
+}
+
+/// Types which could be converted into a `'static` version of themselves.
+///
+/// Used to detach a request from the data it borrows.
+pub trait IntoStatic {
+	/// The `'static` version of `Self`.
+	type Static: 'static;
+
+	/// Convert `self` into [`Self::Static`].
+	fn into_static(self) -> Self::Static;
+}
+impl<T> IntoStatic for Option<T>
+where
+	T: IntoStatic,
+{
+	type Static = Option<T::Static>;
+
+	fn into_static(self) -> Self::Static {
+		self.map(T::into_static)
+	}
+}
+impl<T> IntoStatic for Vec<T>
+where
+	T: IntoStatic,
+{
+	type Static = Vec<T::Static>;
+
+	fn into_static(self) -> Self::Static {
+		self.into_iter().map(T::into_static).collect()
+	}
+}
+impl<K, V> IntoStatic for BTreeMap<K, V>
+where
+	K: IntoStatic,
+	K::Static: Ord,
+	V: IntoStatic,
+{
+	type Static = BTreeMap<K::Static, V::Static>;
+
+	fn into_static(self) -> Self::Static {
+		self.into_iter().map(|(k, v)| (k.into_static(), v.into_static())).collect()
+	}
+}
+impl IntoStatic for Cow<'_, str> {
+	type Static = Cow<'static, str>;
+
+	fn into_static(self) -> Self::Static {
+		Cow::Owned(self.into_owned())
+	}
+}
+macro_rules! impl_into_static_for_primitives {
+	($($ty:ty),*) => {
+		$(
+			impl IntoStatic for $ty {
+				type Static = Self;
+
+				fn into_static(self) -> Self::Static {
+					self
+				}
+			}
+		)*
+	};
+}
+impl_into_static_for_primitives!(
+	bool, u8, u16, u32, u64, i8, i16, i32, i64, Param, Timestamp, Base64
+);
+
+/// Nested parameters which check their own fields.
+///
+/// Called by `ApiExt::validate` for the fields marked with `#[validate]`.
+pub trait Validate {
+	/// Validate the fields before sending the request.
+	fn validate(&self) -> Result<(), Error>;
+}
+
+/// ISO 8601 timestamp in UTC, e.g. `2024-01-01T00:00:00Z`.
+///
+/// Build it from a `time::OffsetDateTime` or a `chrono::DateTime` with the `time` or `chrono`
+/// feature, or from a preformatted string, and convert it back with `TryFrom`.
+///
+/// Response models use it regardless of the features, see `time_date_time` and `chrono_date_time`
+/// to (de)serialize GitHub's timestamps into your own models.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Timestamp(String);
+impl Timestamp {
+	/// Get the [`Timestamp`]'s string representation.
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+impl Display for Timestamp {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		self.0.fmt(f)
+	}
+}
+impl From<&str> for Timestamp {
+	fn from(s: &str) -> Self {
+		Self(s.into())
+	}
+}
+impl From<String> for Timestamp {
+	fn from(s: String) -> Self {
+		Self(s)
+	}
+}
+impl From<&Timestamp> for Timestamp {
+	fn from(timestamp: &Timestamp) -> Self {
+		timestamp.to_owned()
+	}
+}
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+	type Error = time::error::Format;
+
+	fn try_from(date_time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+		let date_time = date_time.to_offset(time::UtcOffset::UTC);
+		let date_time = date_time - time::Duration::nanoseconds(date_time.nanosecond().into());
+
+		date_time.format(&time::format_description::well_known::Rfc3339).map(Self)
+	}
+}
+#[cfg(feature = "time")]
+impl TryFrom<&Timestamp> for time::OffsetDateTime {
+	type Error = time::error::Parse;
+
+	fn try_from(timestamp: &Timestamp) -> Result<Self, Self::Error> {
+		Self::parse(&timestamp.0, &time::format_description::well_known::Rfc3339)
+	}
+}
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for Timestamp
+where
+	Tz: chrono::TimeZone,
+{
+	fn from(date_time: chrono::DateTime<Tz>) -> Self {
+		Self(
+			date_time
+				.with_timezone(&chrono::Utc)
+				.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+		)
+	}
+}
+#[cfg(feature = "chrono")]
+impl TryFrom<&Timestamp> for chrono::DateTime<chrono::Utc> {
+	type Error = chrono::ParseError;
+
+	fn try_from(timestamp: &Timestamp) -> Result<Self, Self::Error> {
+		chrono::DateTime::parse_from_rfc3339(&timestamp.0).map(|date_time| date_time.to_utc())
+	}
+}
+impl ToParam for Timestamp {
+	fn to_param(&self) -> Param {
+		Param::String(self.0.clone())
+	}
+}
+
+macro_rules! impl_serde_date_time {
+	($feature:literal, $module:ident, $ty:ty, $doc:literal) => {
+		#[doc = $doc]
+		#[cfg(all(feature = "serde", feature = $feature))]
+		// `chrono`'s conversion is infallible.
+		#[allow(clippy::unnecessary_fallible_conversions)]
+		pub mod $module {
+			// crates.io
+			use serde::{
+				de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer,
+			};
+			// hack-ink
+			use super::Timestamp;
+
+			#[allow(missing_docs)]
+			pub fn serialize<S>(date_time: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: Serializer,
+			{
+				Timestamp::try_from(*date_time).map_err(S::Error::custom)?.serialize(serializer)
+			}
+
+			#[allow(missing_docs)]
+			pub fn deserialize<'de, D>(deserializer: D) -> Result<$ty, D::Error>
+			where
+				D: Deserializer<'de>,
+			{
+				<$ty>::try_from(&Timestamp::deserialize(deserializer)?).map_err(D::Error::custom)
+			}
+
+			/// The optional version, use it with `#[serde(default, with = "..::option")]`.
+			pub mod option {
+				// crates.io
+				use serde::{Deserialize, Deserializer, Serializer};
+				// hack-ink
+				use super::Timestamp;
+
+				#[allow(missing_docs)]
+				pub fn serialize<S>(
+					date_time: &Option<$ty>,
+					serializer: S,
+				) -> Result<S::Ok, S::Error>
+				where
+					S: Serializer,
+				{
+					match date_time {
+						Some(date_time) => super::serialize(date_time, serializer),
+						None => serializer.serialize_none(),
+					}
+				}
+
+				#[allow(missing_docs)]
+				pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<$ty>, D::Error>
+				where
+					D: Deserializer<'de>,
+				{
+					Option::<Timestamp>::deserialize(deserializer)?
+						.map(|timestamp| {
+							<$ty>::try_from(&timestamp).map_err(serde::de::Error::custom)
+						})
+						.transpose()
+				}
+			}
+		}
+	};
+}
+impl_serde_date_time!(
+	"time",
+	time_date_time,
+	time::OffsetDateTime,
+	"(De)serialize a `time::OffsetDateTime` as GitHub's timestamp, use it with \
+	 `#[serde(with = \"githuber::prelude::time_date_time\")]`."
+);
+impl_serde_date_time!(
+	"chrono",
+	chrono_date_time,
+	chrono::DateTime<chrono::Utc>,
+	"(De)serialize a `chrono::DateTime<chrono::Utc>` as GitHub's timestamp, use it with \
+	 `#[serde(with = \"githuber::prelude::chrono_date_time\")]`."
+);
+
+/// Binary content, sent as a base64 string.
+///
+/// Build it from bytes or a string. With the `serde` feature, it's (de)serialized as a base64
+/// string, so the contents in GitHub's responses are decoded transparently.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Base64(Vec<u8>);
+impl Base64 {
+	/// Decode a base64 string, the line breaks which GitHub inserts are ignored.
+	pub fn decode(s: &str) -> Result<Self, Error> {
+		let s = s.split_ascii_whitespace().collect::<String>();
+
+		STANDARD.decode(s).map(Self).map_err(|e| Error::Decode(e.to_string()))
+	}
+
+	/// Encode the content as a base64 string.
+	pub fn encode(&self) -> String {
+		STANDARD.encode(&self.0)
+	}
+
+	/// Get the decoded bytes.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.0
+	}
+
+	/// Take the decoded bytes.
+	pub fn into_bytes(self) -> Vec<u8> {
+		self.0
+	}
+}
+impl From<&[u8]> for Base64 {
+	fn from(bytes: &[u8]) -> Self {
+		Self(bytes.to_owned())
+	}
+}
+impl From<Vec<u8>> for Base64 {
+	fn from(bytes: Vec<u8>) -> Self {
+		Self(bytes)
+	}
+}
+impl From<&str> for Base64 {
+	fn from(s: &str) -> Self {
+		Self(s.as_bytes().to_owned())
+	}
+}
+impl From<String> for Base64 {
+	fn from(s: String) -> Self {
+		Self(s.into_bytes())
+	}
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Base64 {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.serialize_str(&self.encode())
+	}
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Base64 {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		Self::decode(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+	}
+}
+impl ToParam for Base64 {
+	fn to_param(&self) -> Param {
+		Param::String(self.encode())
+	}
+}
+
+/// List of strings.
+///
+/// Sent as a comma-joined query value or a JSON array. Build it from a slice, an array, a `Vec` or
+/// an iterator of strings, or from a single string which is kept as one item.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct List<'a>(Vec<Cow<'a, str>>);
+impl<'a> List<'a> {
+	/// Get the items.
+	pub fn as_slice(&self) -> &[Cow<'a, str>] {
+		&self.0
+	}
+}
+impl<'a> From<&'a str> for List<'a> {
+	fn from(s: &'a str) -> Self {
+		Self(vec![s.into()])
+	}
+}
+impl From<String> for List<'_> {
+	fn from(s: String) -> Self {
+		Self(vec![s.into()])
+	}
+}
+impl<'a, T> From<&'a [T]> for List<'a>
+where
+	T: AsRef<str>,
+{
+	fn from(items: &'a [T]) -> Self {
+		items.iter().map(AsRef::as_ref).collect()
+	}
+}
+impl<'a, T, const N: usize> From<[T; N]> for List<'a>
+where
+	T: Into<Cow<'a, str>>,
+{
+	fn from(items: [T; N]) -> Self {
+		items.into_iter().collect()
+	}
+}
+impl<'a, T> From<Vec<T>> for List<'a>
+where
+	T: Into<Cow<'a, str>>,
+{
+	fn from(items: Vec<T>) -> Self {
+		items.into_iter().collect()
+	}
+}
+impl<'a, T> FromIterator<T> for List<'a>
+where
+	T: Into<Cow<'a, str>>,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where
+		I: IntoIterator<Item = T>,
+	{
+		Self(iter.into_iter().map(Into::into).collect())
+	}
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for List<'_> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		#[derive(serde::Deserialize)]
+		#[serde(untagged)]
+		enum Repr {
+			/// Kept as one item, like `From<String>`.
+			Item(String),
+			Items(Vec<String>),
+		}
+
+		Ok(match Repr::deserialize(deserializer)? {
+			Repr::Item(item) => item.into(),
+			Repr::Items(items) => items.into(),
+		})
+	}
+}
+impl ToParam for List<'_> {
+	fn to_param(&self) -> Param {
+		Param::Array(self.0.iter().map(ToParam::to_param).collect())
+	}
+}
+impl IntoStatic for List<'_> {
+	type Static = List<'static>;
+
+	fn into_static(self) -> Self::Static {
+		List(self.0.into_iter().map(IntoStatic::into_static).collect())
+	}
+}
+
+/// Sort direction.
+#[api_impl::param]
+pub enum Direction {
+	Asc,
+	Desc,
+}
+
+/// State filter of issues, pull requests and milestones.
+#[api_impl::param]
+pub enum State {
+	Open,
+	Closed,
+	All,
+}
+
+/// Representation of a markdown body, sent as the `Accept` header.
+///
+/// `Full` contains all of the raw markdown, the plain text and the rendered HTML.
+#[api_impl::param]
+pub enum MediaType {
+	#[rename = "application/vnd.github.raw+json"]
+	Raw,
+	#[rename = "application/vnd.github.text+json"]
+	Text,
+	#[rename = "application/vnd.github.html+json"]
+	Html,
+	#[rename = "application/vnd.github.full+json"]
+	Full,
+}
@@ -0,0 +1,684 @@
+//! Pulls related methods.
+//!
+//! GitHub reference(s):
+//! - <https://docs.github.com/en/rest/pulls/pulls>
+//! - <https://docs.github.com/en/rest/pulls/comments>
+//! - <https://docs.github.com/en/rest/pulls/review-requests>
+//! - <https://docs.github.com/en/rest/pulls/reviews>
+
+#[cfg(test)] mod test;
+
+// std
+use std::borrow::Cow;
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls"
+)]
+pub struct ListPullRequests<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	pub state: Option<State>,
+	pub head: Option<&'a str>,
+	pub base: Option<&'a str>,
+	pub sort: Option<PullSort>,
+	pub direction: Option<Direction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls"
+)]
+pub struct CreateAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub head: &'a str,
+	#[payload_ess_param]
+	pub base: &'a str,
+	#[conflicts_with(issue)]
+	pub title: Option<&'a str>,
+	pub head_repo: Option<&'a str>,
+	pub body: Option<&'a str>,
+	pub maintainer_can_modify: Option<bool>,
+	pub draft: Option<bool>,
+	pub issue: Option<u32>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}"
+)]
+pub struct GetAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}"
+)]
+pub struct UpdateAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	pub title: Option<&'a str>,
+	pub body: Option<&'a str>,
+	pub state: Option<PullState>,
+	pub base: Option<&'a str>,
+	pub maintainer_can_modify: Option<bool>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/commits"
+)]
+pub struct ListCommitsOnAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/files"
+)]
+pub struct ListPullRequestsFiles<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+/// GitHub responds `204` if the pull request has been merged, and `404` otherwise.
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/merge",
+	response = "boolean"
+)]
+pub struct CheckIfAPullRequestHasBeenMerged<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/merge"
+)]
+pub struct MergeAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	pub commit_title: Option<&'a str>,
+	pub commit_message: Option<&'a str>,
+	pub sha: Option<&'a str>,
+	pub merge_method: Option<MergeMethod>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/update-branch"
+)]
+pub struct UpdateAPullRequestBranch<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	pub expected_head_sha: Option<&'a str>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments",
+	docs = "https://docs.github.com/en/rest/pulls/comments#list-review-comments-in-a-repository"
+)]
+pub struct ListReviewCommentsInARepository<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	pub sort: Option<ReviewCommentSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments/{}",
+	docs = "https://docs.github.com/en/rest/pulls/comments#get-a-review-comment-for-a-pull-request"
+)]
+pub struct GetAReviewCommentForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments/{}",
+	docs = "https://docs.github.com/en/rest/pulls/comments#update-a-review-comment-for-a-pull-request"
+)]
+pub struct UpdateAReviewCommentForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[payload_ess_param]
+	pub body: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments/{}",
+	docs = "https://docs.github.com/en/rest/pulls/comments#delete-a-review-comment-for-a-pull-request"
+)]
+pub struct DeleteAReviewCommentForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/comments",
+	docs = "https://docs.github.com/en/rest/pulls/comments#list-review-comments-on-a-pull-request"
+)]
+pub struct ListReviewCommentsOnAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	pub sort: Option<ReviewCommentSort>,
+	pub direction: Option<Direction>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/comments",
+	docs = "https://docs.github.com/en/rest/pulls/comments#create-a-review-comment-for-a-pull-request"
+)]
+pub struct CreateAReviewCommentForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[payload_ess_param]
+	pub body: &'a str,
+	#[payload_ess_param]
+	pub commit_id: &'a str,
+	#[payload_ess_param]
+	pub path: &'a str,
+	pub side: Option<Side>,
+	pub line: Option<u32>,
+	pub start_line: Option<u32>,
+	pub start_side: Option<Side>,
+	pub in_reply_to: Option<u64>,
+	pub subject_type: Option<SubjectType>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/comments/{}/replies",
+	docs = "https://docs.github.com/en/rest/pulls/comments#create-a-reply-for-a-review-comment"
+)]
+pub struct CreateAReplyForAReviewComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub comment_id: u64,
+	#[payload_ess_param]
+	pub body: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/requested_reviewers",
+	docs = "https://docs.github.com/en/rest/pulls/review-requests#get-all-requested-reviewers-for-a-pull-request"
+)]
+pub struct GetAllRequestedReviewersForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/requested_reviewers",
+	docs = "https://docs.github.com/en/rest/pulls/review-requests#request-reviewers-for-a-pull-request"
+)]
+pub struct RequestReviewersForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[into]
+	pub reviewers: Option<List<'a>>,
+	#[into]
+	pub team_reviewers: Option<List<'a>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/requested_reviewers",
+	docs = "https://docs.github.com/en/rest/pulls/review-requests#remove-requested-reviewers-from-a-pull-request"
+)]
+pub struct RemoveRequestedReviewersFromAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[payload_ess_param]
+	#[into]
+	pub reviewers: List<'a>,
+	#[into]
+	pub team_reviewers: Option<List<'a>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#list-reviews-for-a-pull-request"
+)]
+pub struct ListReviewsForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+/// Leave `event` empty to create a pending review, which could be submitted later.
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#create-a-review-for-a-pull-request"
+)]
+pub struct CreateAReviewForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	pub commit_id: Option<&'a str>,
+	pub body: Option<&'a str>,
+	pub event: Option<ReviewEvent>,
+	pub comments: Option<Vec<DraftReviewComment<'a>>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews/{}",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#get-a-review-for-a-pull-request"
+)]
+pub struct GetAReviewForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub review_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews/{}",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#update-a-review-for-a-pull-request"
+)]
+pub struct UpdateAReviewForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub review_id: u64,
+	#[payload_ess_param]
+	pub body: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews/{}",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#delete-a-pending-review-for-a-pull-request"
+)]
+pub struct DeleteAPendingReviewForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub review_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#list-comments-for-a-pull-request-review"
+)]
+pub struct ListCommentsForAPullRequestReview<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub review_id: u64,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews/{}/dismissals",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#dismiss-a-review-for-a-pull-request"
+)]
+pub struct DismissAReviewForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub review_id: u64,
+	#[payload_ess_param]
+	pub message: &'a str,
+	pub event: Option<DismissReviewEvent>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "pulls",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/{}/reviews/{}/events",
+	docs = "https://docs.github.com/en/rest/pulls/reviews#submit-a-review-for-a-pull-request"
+)]
+pub struct SubmitAReviewForAPullRequest<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub pull_number: u32,
+	#[path_param]
+	pub review_id: u64,
+	#[payload_ess_param]
+	pub event: ReviewEvent,
+	pub body: Option<&'a str>,
+}
+
+/// What to sort pull requests by.
+#[api_impl::param]
+pub enum PullSort {
+	Created,
+	Updated,
+	Popularity,
+	#[rename = "long-running"]
+	LongRunning,
+}
+
+/// State of a pull request.
+#[api_impl::param]
+pub enum PullState {
+	Open,
+	Closed,
+}
+
+/// Merge method of a pull request.
+#[api_impl::param]
+pub enum MergeMethod {
+	Merge,
+	Squash,
+	Rebase,
+}
+
+/// What to sort review comments by.
+#[api_impl::param]
+pub enum ReviewCommentSort {
+	Created,
+	Updated,
+}
+
+/// Side of the diff which a review comment applies to.
+#[api_impl::param]
+pub enum Side {
+	#[rename = "LEFT"]
+	Left,
+	#[rename = "RIGHT"]
+	Right,
+}
+
+/// Level at which a review comment is targeted.
+#[api_impl::param]
+pub enum SubjectType {
+	Line,
+	File,
+}
+
+/// Action of a review.
+#[api_impl::param]
+pub enum ReviewEvent {
+	#[rename = "APPROVE"]
+	Approve,
+	#[rename = "REQUEST_CHANGES"]
+	RequestChanges,
+	#[rename = "COMMENT"]
+	Comment,
+}
+
+/// Action of [`DismissAReviewForAPullRequest`].
+#[api_impl::param]
+pub enum DismissReviewEvent {
+	#[rename = "DISMISS"]
+	Dismiss,
+}
+
+/// Comment of [`CreateAReviewForAPullRequest`].
+///
+/// Target a line with `line` and `side`, a range with `start_line` and `start_side` as well, or
+/// use the deprecated `position` in the diff.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DraftReviewComment<'a> {
+	#[allow(missing_docs)]
+	pub path: Cow<'a, str>,
+	#[allow(missing_docs)]
+	pub position: Option<u32>,
+	#[allow(missing_docs)]
+	pub body: Cow<'a, str>,
+	#[allow(missing_docs)]
+	pub line: Option<u32>,
+	#[allow(missing_docs)]
+	pub side: Option<Side>,
+	#[allow(missing_docs)]
+	pub start_line: Option<u32>,
+	#[allow(missing_docs)]
+	pub start_side: Option<Side>,
+}
+impl ToParam for DraftReviewComment<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([
+			("path", self.path.to_param()),
+			("position", self.position.to_param()),
+			("body", self.body.to_param()),
+			("line", self.line.to_param()),
+			("side", self.side.to_param()),
+			("start_line", self.start_line.to_param()),
+			("start_side", self.start_side.to_param()),
+		])
+	}
+}
+impl IntoStatic for DraftReviewComment<'_> {
+	type Static = DraftReviewComment<'static>;
+
+	fn into_static(self) -> Self::Static {
+		DraftReviewComment {
+			path: self.path.into_static(),
+			position: self.position,
+			body: self.body.into_static(),
+			line: self.line,
+			side: self.side,
+			start_line: self.start_line,
+			start_side: self.start_side,
+		}
+	}
+}
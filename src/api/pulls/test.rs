@@ -0,0 +1,87 @@
+use super::*;
+
+#[test]
+fn create_a_pull_request_should_work() {
+	let request = create_a_pull_request("hack-ink", "githuber", "dev", "main");
+
+	assert_eq!(request.api(), "https://api.github.com/repos/hack-ink/githuber/pulls");
+	assert_eq!(
+		request.clone().title("Release").draft(true).payload_params(),
+		vec![
+			("head", Param::String("dev".into())),
+			("base", Param::String("main".into())),
+			("title", Param::String("Release".into())),
+			("draft", Param::Bool(true))
+		]
+	);
+	assert_eq!(
+		request.title("Release").issue(1).validate(),
+		Err(Error::Conflict { param: "title", conflicts_with: "issue" })
+	);
+}
+
+#[test]
+fn merge_a_pull_request_should_work() {
+	let request = merge_a_pull_request("hack-ink", "githuber", 1).merge_method(MergeMethod::Squash);
+
+	assert_eq!(request.api(), "https://api.github.com/repos/hack-ink/githuber/pulls/1/merge");
+	assert_eq!(request.payload_params(), vec![("merge_method", Param::String("squash".into()))]);
+}
+
+#[test]
+fn reviews_should_work() {
+	assert_eq!(
+		submit_a_review_for_a_pull_request(
+			"hack-ink",
+			"githuber",
+			1,
+			2,
+			ReviewEvent::RequestChanges
+		)
+		.body("Please add tests.")
+		.payload_params(),
+		vec![
+			("event", Param::String("REQUEST_CHANGES".into())),
+			("body", Param::String("Please add tests.".into()))
+		]
+	);
+	assert_eq!(
+		remove_requested_reviewers_from_a_pull_request("hack-ink", "githuber", 1, ["aurexav"])
+			.payload_params(),
+		vec![("reviewers", Param::Array(vec![Param::String("aurexav".into())]))]
+	);
+	assert_eq!(
+		create_a_review_for_a_pull_request("hack-ink", "githuber", 1)
+			.comments(vec![DraftReviewComment {
+				path: "src/lib.rs".into(),
+				body: "Typo.".into(),
+				line: Some(3),
+				side: Some(Side::Right),
+				..Default::default()
+			}])
+			.payload_params(),
+		vec![(
+			"comments",
+			Param::Array(vec![Param::object([
+				("path", Param::String("src/lib.rs".into())),
+				("body", Param::String("Typo.".into())),
+				("line", Param::Integer(3)),
+				("side", Param::String("RIGHT".into()))
+			])])
+		)]
+	);
+	assert_eq!(
+		create_a_review_comment_for_a_pull_request(
+			"hack-ink",
+			"githuber",
+			1,
+			"Same here.",
+			"6dcb09b",
+			"src/lib.rs"
+		)
+		.in_reply_to(u64::MAX)
+		.payload_params()
+		.pop(),
+		Some(("in_reply_to", Param::UnsignedInteger(u64::MAX)))
+	);
+}
@@ -0,0 +1,306 @@
+//! Reactions related methods.
+//!
+//! GitHub reference(s):
+//! - <https://docs.github.com/en/rest/reactions/reactions>
+
+#[cfg(test)] mod test;
+
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/comments/{}/reactions"
+)]
+pub struct ListReactionsForACommitComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	pub content: Option<Reaction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/comments/{}/reactions"
+)]
+pub struct CreateReactionForACommitComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[payload_ess_param]
+	pub content: Reaction,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/comments/{}/reactions/{}"
+)]
+pub struct DeleteACommitCommentReaction<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[path_param]
+	pub reaction_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments/{}/reactions"
+)]
+pub struct ListReactionsForAnIssueComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	pub content: Option<Reaction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments/{}/reactions"
+)]
+pub struct CreateReactionForAnIssueComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[payload_ess_param]
+	pub content: Reaction,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/comments/{}/reactions/{}"
+)]
+pub struct DeleteAnIssueCommentReaction<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[path_param]
+	pub reaction_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/reactions"
+)]
+pub struct ListReactionsForAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	pub content: Option<Reaction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/reactions"
+)]
+pub struct CreateReactionForAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[payload_ess_param]
+	pub content: Reaction,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/reactions/{}"
+)]
+pub struct DeleteAnIssueReaction<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[path_param]
+	pub reaction_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments/{}/reactions"
+)]
+pub struct ListReactionsForAPullRequestReviewComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	pub content: Option<Reaction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments/{}/reactions"
+)]
+pub struct CreateReactionForAPullRequestReviewComment<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[payload_ess_param]
+	pub content: Reaction,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/pulls/comments/{}/reactions/{}"
+)]
+pub struct DeleteAPullRequestCommentReaction<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub comment_id: u64,
+	#[path_param]
+	pub reaction_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}/reactions"
+)]
+pub struct ListReactionsForARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+	pub content: Option<Reaction>,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}/reactions"
+)]
+pub struct CreateReactionForARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+	#[payload_ess_param]
+	pub content: Reaction,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "reactions",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}/reactions/{}"
+)]
+pub struct DeleteAReleaseReaction<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+	#[path_param]
+	pub reaction_id: u64,
+}
+
+/// Content of a reaction.
+///
+/// Releases only accept `+1`, `laugh`, `heart`, `hooray`, `rocket` and `eyes`.
+#[api_impl::param]
+pub enum Reaction {
+	#[rename = "+1"]
+	PlusOne,
+	#[rename = "-1"]
+	MinusOne,
+	Laugh,
+	Confused,
+	Heart,
+	Hooray,
+	Rocket,
+	Eyes,
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+#[test]
+fn reactions_should_work() {
+	let request = create_reaction_for_an_issue("hack-ink", "githuber", 1, Reaction::PlusOne);
+
+	assert_eq!(request.api(), "https://api.github.com/repos/hack-ink/githuber/issues/1/reactions");
+	assert_eq!(request.payload_params(), vec![("content", Param::String("+1".into()))]);
+	assert_eq!(
+		delete_a_pull_request_comment_reaction("hack-ink", "githuber", 1, 2).api(),
+		"https://api.github.com/repos/hack-ink/githuber/pulls/comments/1/reactions/2"
+	);
+}
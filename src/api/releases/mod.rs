@@ -0,0 +1,265 @@
+//! Releases related methods.
+//!
+//! GitHub reference(s):
+//! - <https://docs.github.com/en/rest/releases/releases>
+//! - <https://docs.github.com/en/rest/releases/assets>
+
+#[cfg(test)] mod test;
+
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases"
+)]
+pub struct ListReleases<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases"
+)]
+pub struct CreateARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub tag_name: &'a str,
+	pub target_commitish: Option<&'a str>,
+	pub name: Option<&'a str>,
+	pub body: Option<&'a str>,
+	pub draft: Option<bool>,
+	pub prerelease: Option<bool>,
+	pub discussion_category_name: Option<&'a str>,
+	pub generate_release_notes: Option<bool>,
+	pub make_latest: Option<MakeLatest>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/assets/{}",
+	docs = "https://docs.github.com/en/rest/releases/assets#get-a-release-asset"
+)]
+pub struct GetAReleaseAsset<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub asset_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/assets/{}",
+	docs = "https://docs.github.com/en/rest/releases/assets#update-a-release-asset"
+)]
+pub struct UpdateAReleaseAsset<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub asset_id: u64,
+	pub name: Option<&'a str>,
+	pub label: Option<&'a str>,
+	pub state: Option<&'a str>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/assets/{}",
+	docs = "https://docs.github.com/en/rest/releases/assets#delete-a-release-asset"
+)]
+pub struct DeleteAReleaseAsset<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub asset_id: u64,
+}
+
+/// The notes are generated without creating a release, pass them to [`CreateARelease::body`].
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/generate-notes"
+)]
+pub struct GenerateReleaseNotesContentForARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[payload_ess_param]
+	pub tag_name: &'a str,
+	pub target_commitish: Option<&'a str>,
+	pub previous_tag_name: Option<&'a str>,
+	pub configuration_file_path: Option<&'a str>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/latest"
+)]
+pub struct GetTheLatestRelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/tags/{}"
+)]
+pub struct GetAReleaseByTagName<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub tag: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}"
+)]
+pub struct GetARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "PATCH",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}"
+)]
+pub struct UpdateARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+	pub tag_name: Option<&'a str>,
+	pub target_commitish: Option<&'a str>,
+	pub name: Option<&'a str>,
+	pub body: Option<&'a str>,
+	pub draft: Option<bool>,
+	pub prerelease: Option<bool>,
+	pub discussion_category_name: Option<&'a str>,
+	pub make_latest: Option<MakeLatest>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}"
+)]
+pub struct DeleteARelease<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}/assets",
+	docs = "https://docs.github.com/en/rest/releases/assets#list-release-assets"
+)]
+pub struct ListReleaseAssets<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+/// The asset's content is sent through the client's `upload` method, together with its
+/// `Content-Type`.
+#[api_impl::api]
+#[properties(
+	category = "releases",
+	method = "POST",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/releases/{}/assets",
+	docs = "https://docs.github.com/en/rest/releases/assets#upload-a-release-asset",
+	base_uri = "https://uploads.github.com",
+	body = "binary"
+)]
+pub struct UploadAReleaseAsset<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub release_id: u64,
+	#[payload_ess_param]
+	pub name: &'a str,
+	pub label: Option<&'a str>,
+}
+
+/// Whether to set a release as the latest release.
+///
+/// `Legacy` tells it by the creation date and the semantic version.
+#[api_impl::param]
+pub enum MakeLatest {
+	True,
+	False,
+	Legacy,
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn upload_a_release_asset_should_work() {
+	let request = upload_a_release_asset("hack-ink", "githuber", 1, "githuber.tar.gz");
+
+	assert_eq!(
+		request.api(),
+		"https://uploads.github.com/repos/hack-ink/githuber/releases/1/assets"
+	);
+	assert_eq!(query_pairs(request.payload_params()), vec![("name", "githuber.tar.gz".into())]);
+	assert_eq!(UploadAReleaseAsset::METADATA.base_uri, "https://uploads.github.com");
+	const { assert!(UploadAReleaseAsset::METADATA.binary_body) };
+}
+
+#[test]
+fn releases_should_work() {
+	assert_eq!(
+		create_a_release("hack-ink", "githuber", "v0.5.0")
+			.generate_release_notes(true)
+			.make_latest(MakeLatest::Legacy)
+			.payload_params(),
+		vec![
+			("tag_name", Param::String("v0.5.0".into())),
+			("generate_release_notes", Param::Bool(true)),
+			("make_latest", Param::String("legacy".into()))
+		]
+	);
+	assert_eq!(
+		get_a_release_by_tag_name("hack-ink", "githuber", "v0.4.4").api(),
+		"https://api.github.com/repos/hack-ink/githuber/releases/tags/v0.4.4"
+	);
+	assert_eq!(
+		delete_a_release_asset("hack-ink", "githuber", 2).api(),
+		"https://api.github.com/repos/hack-ink/githuber/releases/assets/2"
+	);
+}
@@ -0,0 +1,85 @@
+//! Generated by `cargo xtask codegen` from GitHub's OpenAPI description.
+//!
+//! Don't edit it by hand, allowlist the operation in `xtask/spec/allowlist`.
+
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/tarball/{}",
+	docs = "https://docs.github.com/en/rest/repos/contents#download-a-repository-archive-tar",
+	response = "binary"
+)]
+pub struct DownloadARepositoryArchiveTar<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub r#ref: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/zipball/{}",
+	docs = "https://docs.github.com/en/rest/repos/contents#download-a-repository-archive-zip",
+	response = "binary"
+)]
+pub struct DownloadARepositoryArchiveZip<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub r#ref: &'a str,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/tags"
+)]
+pub struct ListRepositoryTags<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[range(1..=100)]
+	pub per_page: Option<u8>,
+	pub page: Option<u16>,
+}
+
+/// GitHub's `tag` schema.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tag {
+	#[allow(missing_docs)]
+	pub name: String,
+	#[allow(missing_docs)]
+	pub commit: TagCommit,
+	#[allow(missing_docs)]
+	pub zipball_url: String,
+	#[allow(missing_docs)]
+	pub tarball_url: String,
+	#[allow(missing_docs)]
+	pub node_id: String,
+}
+
+/// `commit` of [`Tag`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagCommit {
+	#[allow(missing_docs)]
+	pub sha: String,
+	#[allow(missing_docs)]
+	pub url: String,
+}
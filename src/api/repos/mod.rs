@@ -2,9 +2,15 @@
 //!
 //! GitHub reference(s):
 //! - <https://docs.github.com/en/rest/repos>
+//! - <https://docs.github.com/en/rest/repos/contents>
 
 #[cfg(test)] mod test;
 
+mod generated;
+pub use generated::*;
+
+// std
+use std::{borrow::Cow, collections::BTreeMap};
 // hack-ink
 use crate::prelude::*;
 
@@ -18,9 +24,10 @@ use crate::prelude::*;
 pub struct ListOrganizationRepositories<'a> {
 	#[path_param]
 	pub org: &'a str,
-	pub r#type: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
+	pub r#type: Option<OrgRepoType>,
+	pub sort: Option<RepoSort>,
+	pub direction: Option<Direction>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -40,25 +47,27 @@ pub struct CreateAnOrganizationRepository<'a> {
 	pub description: Option<&'a str>,
 	pub homepage: Option<&'a str>,
 	pub private: Option<bool>,
-	pub visibility: Option<&'a str>,
+	pub visibility: Option<Visibility>,
 	pub has_issues: Option<bool>,
 	pub has_projects: Option<bool>,
 	pub has_wiki: Option<bool>,
 	pub has_downloads: Option<bool>,
 	pub is_template: Option<bool>,
-	pub team_id: Option<u8>,
+	pub team_id: Option<u64>,
 	pub auto_init: Option<bool>,
 	pub gitignore_template: Option<&'a str>,
 	pub license_template: Option<&'a str>,
 	pub allow_squash_merge: Option<bool>,
+	pub allow_merge_commit: Option<bool>,
 	pub allow_rebase_merge: Option<bool>,
 	pub allow_auto_merge: Option<bool>,
 	pub delete_branch_on_merge: Option<bool>,
 	pub use_squash_pr_title_as_default: Option<bool>,
-	pub squash_merge_commit_title: Option<&'a str>,
-	pub squash_merge_commit_message: Option<&'a str>,
-	pub merge_commit_title: Option<&'a str>,
-	pub merge_commit_message: Option<&'a str>,
+	pub squash_merge_commit_title: Option<SquashMergeCommitTitle>,
+	pub squash_merge_commit_message: Option<SquashMergeCommitMessage>,
+	pub merge_commit_title: Option<MergeCommitTitle>,
+	pub merge_commit_message: Option<MergeCommitMessage>,
+	pub custom_properties: Option<BTreeMap<Cow<'a, str>, CustomPropertyValue<'a>>>,
 }
 
 #[api_impl::api]
@@ -91,7 +100,7 @@ pub struct UpdateARepository<'a> {
 	pub description: Option<&'a str>,
 	pub homepage: Option<&'a str>,
 	pub private: Option<bool>,
-	pub visibility: Option<&'a str>,
+	pub visibility: Option<Visibility>,
 	pub security_and_analysis: Option<&'a str>,
 	pub has_issues: Option<bool>,
 	pub has_projects: Option<bool>,
@@ -105,10 +114,10 @@ pub struct UpdateARepository<'a> {
 	pub delete_branch_on_merge: Option<bool>,
 	pub allow_update_branch: Option<bool>,
 	pub use_squash_pr_title_as_default: Option<bool>,
-	pub squash_merge_commit_title: Option<&'a str>,
-	pub squash_merge_commit_message: Option<&'a str>,
-	pub merge_commit_title: Option<&'a str>,
-	pub merge_commit_message: Option<&'a str>,
+	pub squash_merge_commit_title: Option<SquashMergeCommitTitle>,
+	pub squash_merge_commit_message: Option<SquashMergeCommitMessage>,
+	pub merge_commit_title: Option<MergeCommitTitle>,
+	pub merge_commit_message: Option<MergeCommitMessage>,
 	pub archived: Option<bool>,
 	pub allow_forking: Option<bool>,
 	pub web_commit_signoff_required: Option<bool>,
@@ -133,9 +142,10 @@ pub struct DeleteARepository<'a> {
 	category = "repos",
 	method = "PUT",
 	accept = "application/vnd.github+json",
-	uri = "/repos/{}/{}/automated-security-fixes"
+	uri = "/repos/{}/{}/automated-security-fixes",
+	docs = "https://docs.github.com/en/rest/repos/repos#enable-dependabot-security-updates"
 )]
-pub struct EnableAutomatedSecurityFixes<'a> {
+pub struct EnableDependabotSecurityUpdates<'a> {
 	#[path_param]
 	pub owner: &'a str,
 	#[path_param]
@@ -147,9 +157,10 @@ pub struct EnableAutomatedSecurityFixes<'a> {
 	category = "repos",
 	method = "DELETE",
 	accept = "application/vnd.github+json",
-	uri = "/repos/{}/{}/automated-security-fixes"
+	uri = "/repos/{}/{}/automated-security-fixes",
+	docs = "https://docs.github.com/en/rest/repos/repos#disable-dependabot-security-updates"
 )]
-pub struct DeleteAutomatedSecurityFixes<'a> {
+pub struct DisableDependabotSecurityUpdates<'a> {
 	#[path_param]
 	pub owner: &'a str,
 	#[path_param]
@@ -161,9 +172,10 @@ pub struct DeleteAutomatedSecurityFixes<'a> {
 	category = "repos",
 	method = "GET",
 	accept = "application/vnd.github+json",
-	uri = "/repos/{}/{}/codeowners/errors"
+	uri = "/repos/{}/{}/codeowners/errors",
+	docs = "https://docs.github.com/en/rest/repos/repos#list-codeowners-errors"
 )]
-pub struct ListCodeownersError<'a> {
+pub struct ListCodeownersErrors<'a> {
 	#[path_param]
 	pub owner: &'a str,
 	#[path_param]
@@ -171,6 +183,77 @@ pub struct ListCodeownersError<'a> {
 	pub r#ref: Option<&'a str>,
 }
 
+/// Deserialize the response into [`RepositoryContent`] to decode the file's content.
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/contents/{}",
+	docs = "https://docs.github.com/en/rest/repos/contents#get-repository-content"
+)]
+pub struct GetRepositoryContent<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub path: &'a str,
+	pub r#ref: Option<&'a str>,
+}
+
+/// `sha` of the replaced file is required to update it, GitHub rejects the update if the file has
+/// been changed since.
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/contents/{}",
+	docs = "https://docs.github.com/en/rest/repos/contents#create-or-update-file-contents"
+)]
+pub struct CreateOrUpdateFileContents<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub path: &'a str,
+	#[payload_ess_param]
+	pub message: &'a str,
+	#[payload_ess_param]
+	#[into]
+	pub content: Base64,
+	pub sha: Option<&'a str>,
+	pub branch: Option<&'a str>,
+	pub committer: Option<CommitAuthor<'a>>,
+	pub author: Option<CommitAuthor<'a>>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "DELETE",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/contents/{}",
+	docs = "https://docs.github.com/en/rest/repos/contents#delete-a-file"
+)]
+pub struct DeleteAFile<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub path: &'a str,
+	#[payload_ess_param]
+	pub message: &'a str,
+	#[payload_ess_param]
+	pub sha: &'a str,
+	pub branch: Option<&'a str>,
+	pub committer: Option<CommitAuthor<'a>>,
+	pub author: Option<CommitAuthor<'a>>,
+}
+
 #[api_impl::api]
 #[properties(
 	category = "repos",
@@ -184,6 +267,7 @@ pub struct ListRepositoryContributors<'a> {
 	#[path_param]
 	pub repo: &'a str,
 	pub anon: Option<bool>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -202,8 +286,7 @@ pub struct CreateARepositoryDispatchEvent<'a> {
 	pub repo: &'a str,
 	#[payload_ess_param]
 	pub event_type: &'a str,
-	// TODO: JSON?
-	pub client_payload: Option<&'a str>,
+	pub client_payload: Option<BTreeMap<Cow<'a, str>, Param>>,
 }
 
 #[api_impl::api]
@@ -225,13 +308,33 @@ pub struct ListRepositoryLanguages<'a> {
 	category = "repos",
 	method = "GET",
 	accept = "application/vnd.github+json",
-	uri = "/repos/{}/{}/tags"
+	uri = "/repos/{}/{}/readme",
+	docs = "https://docs.github.com/en/rest/repos/contents#get-a-repository-readme"
+)]
+pub struct GetARepositoryReadme<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	pub r#ref: Option<&'a str>,
+}
+
+#[api_impl::api]
+#[properties(
+	category = "repos",
+	method = "GET",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/readme/{}",
+	docs = "https://docs.github.com/en/rest/repos/contents#get-a-repository-readme-for-a-directory"
 )]
-pub struct ListRepositoryTags<'a> {
+pub struct GetARepositoryReadmeForADirectory<'a> {
 	#[path_param]
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
+	#[path_param(multi_segment)]
+	pub dir: &'a str,
+	pub r#ref: Option<&'a str>,
 }
 
 #[api_impl::api]
@@ -246,6 +349,7 @@ pub struct ListRepositoryTeams<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -262,6 +366,7 @@ pub struct GetAllRepositoryTopics<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
@@ -278,9 +383,9 @@ pub struct ReplaceAllRepositoryTopics<'a> {
 	pub owner: &'a str,
 	#[path_param]
 	pub repo: &'a str,
-	// TODO: JSON?
 	#[payload_ess_param]
-	pub names: &'a str,
+	#[into]
+	pub names: List<'a>,
 }
 
 #[api_impl::api]
@@ -298,8 +403,8 @@ pub struct TransferARepository<'a> {
 	#[payload_ess_param]
 	pub new_owner: &'a str,
 	pub new_name: Option<&'a str>,
-	// TODO: JSON?
-	pub team_ids: Option<&'a str>,
+	#[into]
+	pub team_ids: Option<Vec<u64>>,
 }
 
 #[api_impl::api]
@@ -307,7 +412,8 @@ pub struct TransferARepository<'a> {
 	category = "repos",
 	method = "GET",
 	accept = "application/vnd.github+json",
-	uri = "/repos/{}/{}/vulnerability-alerts"
+	uri = "/repos/{}/{}/vulnerability-alerts",
+	response = "boolean"
 )]
 pub struct CheckIfVulnerabilityAlertsAreEnabledForARepository<'a> {
 	#[path_param]
@@ -372,7 +478,7 @@ pub struct CreateARepositoryUsingATemplate<'a> {
 	uri = "/repositories"
 )]
 pub struct ListPublicRepositories {
-	pub since: Option<u32>,
+	pub since: Option<u64>,
 }
 
 #[api_impl::api]
@@ -383,15 +489,20 @@ pub struct ListPublicRepositories {
 	uri = "/user/repos"
 )]
 pub struct ListRepositoriesForTheAuthenticatedUser<'a> {
-	pub visibility: Option<&'a str>,
-	pub affiliation: Option<&'a str>,
-	pub r#type: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
+	pub visibility: Option<VisibilityFilter>,
+	#[into]
+	pub affiliation: Option<List<'a>>,
+	#[conflicts_with(visibility, affiliation)]
+	pub r#type: Option<RepoType>,
+	pub sort: Option<RepoSort>,
+	pub direction: Option<Direction>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
-	pub since: Option<&'a str>,
-	pub before: Option<&'a str>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[into]
+	pub before: Option<Timestamp>,
 }
 
 #[api_impl::api]
@@ -411,7 +522,7 @@ pub struct CreateARepositoryForTheAuthenticatedUser<'a> {
 	pub has_projects: Option<bool>,
 	pub has_wiki: Option<bool>,
 	pub has_discussions: Option<bool>,
-	pub team_id: Option<u8>,
+	pub team_id: Option<u64>,
 	pub auto_init: Option<bool>,
 	pub gitignore_template: Option<&'a str>,
 	pub license_template: Option<&'a str>,
@@ -420,10 +531,10 @@ pub struct CreateARepositoryForTheAuthenticatedUser<'a> {
 	pub allow_rebase_merge: Option<bool>,
 	pub allow_auto_merge: Option<bool>,
 	pub delete_branch_on_merge: Option<bool>,
-	pub squash_merge_commit_title: Option<&'a str>,
-	pub squash_merge_commit_message: Option<&'a str>,
-	pub merge_commit_title: Option<&'a str>,
-	pub merge_commit_message: Option<&'a str>,
+	pub squash_merge_commit_title: Option<SquashMergeCommitTitle>,
+	pub squash_merge_commit_message: Option<SquashMergeCommitMessage>,
+	pub merge_commit_title: Option<MergeCommitTitle>,
+	pub merge_commit_message: Option<MergeCommitMessage>,
 	pub has_downloads: Option<bool>,
 	pub is_template: Option<bool>,
 }
@@ -438,9 +549,231 @@ pub struct CreateARepositoryForTheAuthenticatedUser<'a> {
 pub struct ListRepositoriesForAUser<'a> {
 	#[path_param]
 	pub username: &'a str,
-	pub r#type: Option<&'a str>,
-	pub sort: Option<&'a str>,
-	pub direction: Option<&'a str>,
+	pub r#type: Option<RepoType>,
+	pub sort: Option<RepoSort>,
+	pub direction: Option<Direction>,
+	#[range(1..=100)]
 	pub per_page: Option<u8>,
 	pub page: Option<u16>,
 }
+
+/// Type of repositories to list within an organization.
+#[api_impl::param]
+pub enum OrgRepoType {
+	All,
+	Public,
+	Private,
+	Forks,
+	Sources,
+	Member,
+}
+
+/// Type of repositories to list for a user.
+#[api_impl::param]
+pub enum RepoType {
+	All,
+	Owner,
+	Public,
+	Private,
+	Member,
+}
+
+/// What to sort repositories by.
+#[api_impl::param]
+pub enum RepoSort {
+	Created,
+	Updated,
+	Pushed,
+	FullName,
+}
+
+/// Visibility of a repository.
+#[api_impl::param]
+pub enum Visibility {
+	Public,
+	Private,
+	Internal,
+}
+
+/// Visibility filter of repositories.
+#[api_impl::param]
+pub enum VisibilityFilter {
+	All,
+	Public,
+	Private,
+}
+
+/// Default value for a squash merge commit title.
+#[api_impl::param]
+pub enum SquashMergeCommitTitle {
+	#[rename = "PR_TITLE"]
+	PrTitle,
+	#[rename = "COMMIT_OR_PR_TITLE"]
+	CommitOrPrTitle,
+}
+
+/// Default value for a squash merge commit message.
+#[api_impl::param]
+pub enum SquashMergeCommitMessage {
+	#[rename = "PR_BODY"]
+	PrBody,
+	#[rename = "COMMIT_MESSAGES"]
+	CommitMessages,
+	#[rename = "BLANK"]
+	Blank,
+}
+
+/// Default value for a merge commit title.
+#[api_impl::param]
+pub enum MergeCommitTitle {
+	#[rename = "PR_TITLE"]
+	PrTitle,
+	#[rename = "MERGE_MESSAGE"]
+	MergeMessage,
+}
+
+/// Default value for a merge commit message.
+#[api_impl::param]
+pub enum MergeCommitMessage {
+	#[rename = "PR_BODY"]
+	PrBody,
+	#[rename = "PR_TITLE"]
+	PrTitle,
+	#[rename = "BLANK"]
+	Blank,
+}
+
+/// Value of a repository's custom property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum CustomPropertyValue<'a> {
+	/// Value of a string or single select property.
+	Single(Cow<'a, str>),
+	/// Values of a multi select property.
+	Multiple(List<'a>),
+}
+impl ToParam for CustomPropertyValue<'_> {
+	fn to_param(&self) -> Param {
+		match self {
+			Self::Single(value) => value.to_param(),
+			Self::Multiple(values) => values.to_param(),
+		}
+	}
+}
+impl IntoStatic for CustomPropertyValue<'_> {
+	type Static = CustomPropertyValue<'static>;
+
+	fn into_static(self) -> Self::Static {
+		match self {
+			Self::Single(value) => CustomPropertyValue::Single(value.into_static()),
+			Self::Multiple(values) => CustomPropertyValue::Multiple(values.into_static()),
+		}
+	}
+}
+
+/// `committer` or `author` of a commit, the authenticated user by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommitAuthor<'a> {
+	#[allow(missing_docs)]
+	pub name: Cow<'a, str>,
+	#[allow(missing_docs)]
+	pub email: Cow<'a, str>,
+	#[allow(missing_docs)]
+	pub date: Option<Timestamp>,
+}
+impl ToParam for CommitAuthor<'_> {
+	fn to_param(&self) -> Param {
+		Param::object([
+			("name", self.name.to_param()),
+			("email", self.email.to_param()),
+			("date", self.date.to_param()),
+		])
+	}
+}
+impl IntoStatic for CommitAuthor<'_> {
+	type Static = CommitAuthor<'static>;
+
+	fn into_static(self) -> Self::Static {
+		CommitAuthor {
+			name: self.name.into_static(),
+			email: self.email.into_static(),
+			date: self.date,
+		}
+	}
+}
+
+/// Response of [`GetRepositoryContent`], [`GetARepositoryReadme`] and
+/// [`GetARepositoryReadmeForADirectory`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum RepositoryContent {
+	/// Entries of a directory, without the files' content.
+	Directory(Vec<ContentEntry>),
+	/// A single entry.
+	Entry(ContentEntry),
+}
+
+/// An entry of [`RepositoryContent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum ContentEntry {
+	#[allow(missing_docs)]
+	File(ContentFile),
+	#[allow(missing_docs)]
+	Dir(ContentInfo),
+	#[allow(missing_docs)]
+	Symlink(ContentSymlink),
+	#[allow(missing_docs)]
+	Submodule(ContentSubmodule),
+}
+
+/// Common fields of the [`ContentEntry`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentInfo {
+	#[allow(missing_docs)]
+	pub name: String,
+	#[allow(missing_docs)]
+	pub path: String,
+	/// Blob SHA, pass it to [`CreateOrUpdateFileContents`] or [`DeleteAFile`].
+	pub sha: String,
+	#[allow(missing_docs)]
+	pub size: u64,
+}
+
+/// A file of [`ContentEntry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentFile {
+	#[allow(missing_docs)]
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub info: ContentInfo,
+	/// Decoded content, absent from the directory listings and empty for the files over 1 MB.
+	pub content: Option<Base64>,
+}
+
+/// A symlink of [`ContentEntry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentSymlink {
+	#[allow(missing_docs)]
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub info: ContentInfo,
+	/// Absent from the directory listings.
+	pub target: Option<String>,
+}
+
+/// A submodule of [`ContentEntry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentSubmodule {
+	#[allow(missing_docs)]
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub info: ContentInfo,
+	/// Absent from the directory listings.
+	pub submodule_git_url: Option<String>,
+}
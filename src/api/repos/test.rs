@@ -5,3 +5,206 @@ use crate::test_util::*;
 async fn list_organization_repositories_should_work() {
 	api_client().unwrap().get(list_organization_repositories("hack-ink")).await.unwrap();
 }
+
+#[test]
+fn validate_should_work() {
+	assert_eq!(
+		list_organization_repositories("hack-ink").per_page(200).validate(),
+		Err(Error::OutOfRange { param: "per_page", value: "200".into(), range: "1..=100" })
+	);
+	assert_eq!(
+		list_repositories_for_the_authenticated_user()
+			.visibility(VisibilityFilter::Public)
+			.r#type(RepoType::Owner)
+			.validate(),
+		Err(Error::Conflict { param: "type", conflicts_with: "visibility" })
+	);
+	assert_eq!(list_organization_repositories("hack-ink").per_page(100).validate(), Ok(()));
+}
+
+#[test]
+fn headers_should_work() {
+	#[api_impl::api]
+	#[properties(
+		category = "repos",
+		method = "GET",
+		accept = "application/vnd.github+json",
+		uri = "/repos/{}/{}"
+	)]
+	pub struct GetARepositoryIfNoneMatch<'a> {
+		#[path_param]
+		pub owner: &'a str,
+		#[path_param]
+		pub repo: &'a str,
+		#[header_param("If-None-Match")]
+		pub if_none_match: &'a str,
+		#[header_param("X-GitHub-Api-Version")]
+		pub api_version: Option<&'a str>,
+	}
+
+	let request = get_a_repository_if_none_match("hack-ink", "githuber", "\"etag\"");
+
+	assert_eq!(request.headers(), vec![("If-None-Match", "\"etag\"".into())]);
+	assert_eq!(
+		request.api_version("2022-11-28").headers(),
+		vec![("If-None-Match", "\"etag\"".into()), ("X-GitHub-Api-Version", "2022-11-28".into())]
+	);
+}
+
+#[test]
+fn contents_should_work() {
+	let request = create_or_update_file_contents(
+		"hack-ink",
+		"githuber",
+		"config.toml",
+		"Sync config",
+		b"[sync]\n".as_slice(),
+	);
+
+	assert_eq!(
+		request.api(),
+		"https://api.github.com/repos/hack-ink/githuber/contents/config.toml"
+	);
+	assert_eq!(
+		request
+			.committer(CommitAuthor {
+				name: "GitHuber".into(),
+				email: "githuber@hack.ink".into(),
+				date: None
+			})
+			.payload_params(),
+		vec![
+			("message", Param::String("Sync config".into())),
+			("content", Param::String("W3N5bmNdCg==".into())),
+			(
+				"committer",
+				Param::object([
+					("name", Param::String("GitHuber".into())),
+					("email", Param::String("githuber@hack.ink".into()))
+				])
+			)
+		]
+	);
+	assert_eq!(
+		download_a_repository_archive_tar("hack-ink", "githuber", "feature/x").api(),
+		"https://api.github.com/repos/hack-ink/githuber/tarball/feature/x"
+	);
+	assert_eq!(
+		download_a_repository_archive_zip("hack-ink", "githuber", "tags/v1.0").api(),
+		"https://api.github.com/repos/hack-ink/githuber/zipball/tags/v1.0"
+	);
+	assert_eq!(
+		get_repository_content("hack-ink", "githuber", "docs/read me.md").api(),
+		"https://api.github.com/repos/hack-ink/githuber/contents/docs/read%20me.md"
+	);
+	assert_eq!(Base64::decode("W3N5\nbmNd\nCg==\n").unwrap().as_bytes(), b"[sync]\n");
+	assert!(matches!(Base64::decode("!"), Err(Error::Decode(_))));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn repository_content_should_deserialize() {
+	let file = serde_json::from_value::<RepositoryContent>(serde_json::json!({
+		"type": "file",
+		"encoding": "base64",
+		"size": 7,
+		"name": "config.toml",
+		"path": "config.toml",
+		"content": "W3N5bmNdCg==\n",
+		"sha": "3d21ec53a331a6f037a91c368710b99387d012c1"
+	}))
+	.unwrap();
+	let RepositoryContent::Entry(ContentEntry::File(file)) = file else { panic!("expect a file") };
+
+	assert_eq!(file.content.unwrap().as_bytes(), b"[sync]\n");
+
+	let dir = serde_json::from_value::<RepositoryContent>(serde_json::json!([
+		{ "type": "file", "size": 7, "name": "config.toml", "path": "config.toml", "sha": "3d21" },
+		{ "type": "dir", "size": 0, "name": "src", "path": "src", "sha": "3d22" }
+	]))
+	.unwrap();
+	let RepositoryContent::Directory(entries) = dir else { panic!("expect a directory") };
+
+	assert!(matches!(&entries[1], ContentEntry::Dir(info) if info.name == "src"));
+}
+
+#[test]
+fn custom_properties_should_work() {
+	let request = create_an_organization_repository("hack-ink", "githuber").custom_properties(
+		[
+			("team".into(), CustomPropertyValue::Single("infra".into())),
+			("langs".into(), CustomPropertyValue::Multiple(["rust", "ts"].into())),
+		]
+		.into(),
+	);
+
+	assert_eq!(
+		request.payload_params(),
+		vec![
+			("name", Param::String("githuber".into())),
+			(
+				"custom_properties",
+				Param::object([
+					(
+						"langs",
+						Param::Array(vec![
+							Param::String("rust".into()),
+							Param::String("ts".into())
+						])
+					),
+					("team", Param::String("infra".into())),
+				])
+			)
+		]
+	);
+}
+
+#[test]
+fn rename_should_work() {
+	#[api_impl::api]
+	#[properties(
+		category = "repos",
+		method = "GET",
+		accept = "application/vnd.github+json",
+		uri = "/repos/{}/{}/hooks"
+	)]
+	pub struct ListRepositoryWebhooksRenamed<'a> {
+		#[path_param]
+		pub owner: &'a str,
+		#[path_param]
+		pub repo: &'a str,
+		#[rename = "self"]
+		#[payload_ess_param]
+		pub self_: bool,
+	}
+
+	assert_eq!(
+		list_repository_webhooks_renamed("hack-ink", "githuber", true).payload_params(),
+		vec![("self", Param::Bool(true))]
+	);
+}
+
+#[test]
+fn transfer_and_dispatch_should_work() {
+	assert_eq!(
+		transfer_a_repository("hack-ink", "githuber", "aurexav")
+			.team_ids([12, 345])
+			.payload_params(),
+		vec![
+			("new_owner", Param::String("aurexav".into())),
+			(
+				"team_ids",
+				Param::Array(vec![Param::UnsignedInteger(12), Param::UnsignedInteger(345)])
+			)
+		]
+	);
+	assert_eq!(
+		create_a_repository_dispatch_event("hack-ink", "githuber", "deploy")
+			.client_payload([("env".into(), Param::String("prod".into()))].into())
+			.payload_params(),
+		vec![
+			("event_type", Param::String("deploy".into())),
+			("client_payload", Param::object([("env", Param::String("prod".into()))]))
+		]
+	);
+}
@@ -0,0 +1,1757 @@
+//! Resource handles, which fill the common parameters of the requests.
+
+// std
+use std::borrow::Cow;
+// hack-ink
+use crate::{
+	api::{
+		actions::*, branches::*, commits::*, issues::*, pulls::*, reactions::*, releases::*,
+		repos::*,
+	},
+	client::{Client, Request},
+	prelude::*,
+};
+
+/// Handle of a repository.
+#[derive(Debug, Clone)]
+pub struct Repo<'c, 'a> {
+	pub(super) client: &'c Client,
+	pub(super) owner: Cow<'a, str>,
+	pub(super) repo: Cow<'a, str>,
+}
+impl<'c, 'a> Repo<'c, 'a> {
+	/// Handle of the repository's branches.
+	pub fn branches(&self) -> RepoBranches<'c, 'a> {
+		RepoBranches(self.clone())
+	}
+
+	/// Handle of the repository's commits.
+	pub fn commits(&self) -> RepoCommits<'c, 'a> {
+		RepoCommits(self.clone())
+	}
+
+	/// Handle of the repository's contents.
+	pub fn contents(&self) -> RepoContents<'c, 'a> {
+		RepoContents(self.clone())
+	}
+
+	/// Handle of the repository's issues.
+	pub fn issues(&self) -> RepoIssues<'c, 'a> {
+		RepoIssues(self.clone())
+	}
+
+	/// Handle of the repository's labels.
+	pub fn labels(&self) -> RepoLabels<'c, 'a> {
+		RepoLabels(self.clone())
+	}
+
+	/// Handle of the repository's milestones.
+	pub fn milestones(&self) -> RepoMilestones<'c, 'a> {
+		RepoMilestones(self.clone())
+	}
+
+	/// Handle of the repository's pull requests.
+	pub fn pulls(&self) -> RepoPulls<'c, 'a> {
+		RepoPulls(self.clone())
+	}
+
+	/// Handle of the repository's reactions.
+	pub fn reactions(&self) -> RepoReactions<'c, 'a> {
+		RepoReactions(self.clone())
+	}
+
+	/// Handle of the repository's releases.
+	pub fn releases(&self) -> RepoReleases<'c, 'a> {
+		RepoReleases(self.clone())
+	}
+
+	/// [`GetARepository`].
+	pub fn get(&self) -> Request<'c, GetARepository<'a>> {
+		self.request(get_a_repository(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`UpdateARepository`].
+	pub fn update(&self) -> Request<'c, UpdateARepository<'a>> {
+		self.request(update_a_repository(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`DeleteARepository`].
+	pub fn delete(&self) -> Request<'c, DeleteARepository<'a>> {
+		self.request(delete_a_repository(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`EnableDependabotSecurityUpdates`].
+	pub fn enable_dependabot_security_updates(
+		&self,
+	) -> Request<'c, EnableDependabotSecurityUpdates<'a>> {
+		self.request(enable_dependabot_security_updates(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`DisableDependabotSecurityUpdates`].
+	pub fn disable_dependabot_security_updates(
+		&self,
+	) -> Request<'c, DisableDependabotSecurityUpdates<'a>> {
+		self.request(disable_dependabot_security_updates(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`ListCodeownersErrors`].
+	pub fn codeowners_errors(&self) -> Request<'c, ListCodeownersErrors<'a>> {
+		self.request(list_codeowners_errors(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`ListRepositoryContributors`].
+	pub fn contributors(&self) -> Request<'c, ListRepositoryContributors<'a>> {
+		self.request(list_repository_contributors(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`CreateARepositoryDispatchEvent`].
+	pub fn dispatch(
+		&self,
+		event_type: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateARepositoryDispatchEvent<'a>> {
+		self.request(create_a_repository_dispatch_event(
+			self.owner.clone(),
+			self.repo.clone(),
+			event_type,
+		))
+	}
+
+	/// [`ListRepositoryLanguages`].
+	pub fn languages(&self) -> Request<'c, ListRepositoryLanguages<'a>> {
+		self.request(list_repository_languages(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`ListRepositoryTags`].
+	pub fn tags(&self) -> Request<'c, ListRepositoryTags<'a>> {
+		self.request(list_repository_tags(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`DownloadARepositoryArchiveTar`], send it with [`Request::download`].
+	pub fn tarball(
+		&self,
+		r#ref: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DownloadARepositoryArchiveTar<'a>> {
+		self.request(download_a_repository_archive_tar(
+			self.owner.clone(),
+			self.repo.clone(),
+			r#ref,
+		))
+	}
+
+	/// [`ListRepositoryTeams`].
+	pub fn teams(&self) -> Request<'c, ListRepositoryTeams<'a>> {
+		self.request(list_repository_teams(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`GetAllRepositoryTopics`].
+	pub fn topics(&self) -> Request<'c, GetAllRepositoryTopics<'a>> {
+		self.request(get_all_repository_topics(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`ReplaceAllRepositoryTopics`].
+	pub fn replace_topics(
+		&self,
+		names: impl Into<List<'a>>,
+	) -> Request<'c, ReplaceAllRepositoryTopics<'a>> {
+		self.request(replace_all_repository_topics(self.owner.clone(), self.repo.clone(), names))
+	}
+
+	/// [`TransferARepository`].
+	pub fn transfer(
+		&self,
+		new_owner: impl Into<Cow<'a, str>>,
+	) -> Request<'c, TransferARepository<'a>> {
+		self.request(transfer_a_repository(self.owner.clone(), self.repo.clone(), new_owner))
+	}
+
+	/// [`CheckIfVulnerabilityAlertsAreEnabledForARepository`], send it with [`Request::check`].
+	pub fn vulnerability_alerts(
+		&self,
+	) -> Request<'c, CheckIfVulnerabilityAlertsAreEnabledForARepository<'a>> {
+		self.request(check_if_vulnerability_alerts_are_enabled_for_a_repository(
+			self.owner.clone(),
+			self.repo.clone(),
+		))
+	}
+
+	/// [`EnableVulnerabilityAlerts`].
+	pub fn enable_vulnerability_alerts(&self) -> Request<'c, EnableVulnerabilityAlerts<'a>> {
+		self.request(enable_vulnerability_alerts(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`DisableVulnerabilityAlerts`].
+	pub fn disable_vulnerability_alerts(&self) -> Request<'c, DisableVulnerabilityAlerts<'a>> {
+		self.request(disable_vulnerability_alerts(self.owner.clone(), self.repo.clone()))
+	}
+
+	/// [`DownloadARepositoryArchiveZip`], send it with [`Request::download`].
+	pub fn zipball(
+		&self,
+		r#ref: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DownloadARepositoryArchiveZip<'a>> {
+		self.request(download_a_repository_archive_zip(
+			self.owner.clone(),
+			self.repo.clone(),
+			r#ref,
+		))
+	}
+
+	/// [`DownloadAnArtifact`] as a zip, send it with [`Request::download`].
+	pub fn artifact(&self, artifact_id: u64) -> Request<'c, DownloadAnArtifact<'a>> {
+		self.request(download_an_artifact(
+			self.owner.clone(),
+			self.repo.clone(),
+			artifact_id,
+			"zip",
+		))
+	}
+
+	/// [`CreateARepositoryUsingATemplate`], with this repository as the template.
+	pub fn generate(
+		&self,
+		name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateARepositoryUsingATemplate<'a>> {
+		self.request(create_a_repository_using_a_template(
+			self.owner.clone(),
+			self.repo.clone(),
+			name,
+		))
+	}
+
+	fn request<R>(&self, request: R) -> Request<'c, R>
+	where
+		R: ApiExt,
+	{
+		Request::new(self.client, request)
+	}
+}
+
+/// Handle of a repository's branches.
+#[derive(Debug, Clone)]
+pub struct RepoBranches<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoBranches<'c, 'a> {
+	/// [`ListBranches`].
+	pub fn list(&self) -> Request<'c, ListBranches<'a>> {
+		self.0.request(list_branches(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`GetABranch`].
+	pub fn get(&self, branch: impl Into<Cow<'a, str>>) -> Request<'c, GetABranch<'a>> {
+		self.0.request(get_a_branch(self.0.owner.clone(), self.0.repo.clone(), branch))
+	}
+
+	/// [`GetBranchProtection`].
+	pub fn protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetBranchProtection<'a>> {
+		self.0.request(get_branch_protection(self.0.owner.clone(), self.0.repo.clone(), branch))
+	}
+
+	/// [`UpdateBranchProtection`].
+	pub fn update_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		required_status_checks: Option<RequiredStatusChecks<'a>>,
+		enforce_admins: Option<bool>,
+		required_pull_request_reviews: Option<RequiredPullRequestReviews<'a>>,
+		restrictions: Option<PushRestrictions<'a>>,
+	) -> Request<'c, UpdateBranchProtection<'a>> {
+		self.0.request(update_branch_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			required_status_checks,
+			enforce_admins,
+			required_pull_request_reviews,
+			restrictions,
+		))
+	}
+
+	/// [`DeleteBranchProtection`].
+	pub fn delete_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DeleteBranchProtection<'a>> {
+		self.0.request(delete_branch_protection(self.0.owner.clone(), self.0.repo.clone(), branch))
+	}
+
+	/// [`GetAdminBranchProtection`].
+	pub fn admin_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetAdminBranchProtection<'a>> {
+		self.0.request(get_admin_branch_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`SetAdminBranchProtection`].
+	pub fn set_admin_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, SetAdminBranchProtection<'a>> {
+		self.0.request(set_admin_branch_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`DeleteAdminBranchProtection`].
+	pub fn delete_admin_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DeleteAdminBranchProtection<'a>> {
+		self.0.request(delete_admin_branch_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`GetPullRequestReviewProtection`].
+	pub fn review_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetPullRequestReviewProtection<'a>> {
+		self.0.request(get_pull_request_review_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`UpdatePullRequestReviewProtection`].
+	pub fn update_review_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, UpdatePullRequestReviewProtection<'a>> {
+		self.0.request(update_pull_request_review_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`DeletePullRequestReviewProtection`].
+	pub fn delete_review_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DeletePullRequestReviewProtection<'a>> {
+		self.0.request(delete_pull_request_review_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`GetCommitSignatureProtection`].
+	pub fn signature_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetCommitSignatureProtection<'a>> {
+		self.0.request(get_commit_signature_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`CreateCommitSignatureProtection`].
+	pub fn create_signature_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateCommitSignatureProtection<'a>> {
+		self.0.request(create_commit_signature_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`DeleteCommitSignatureProtection`].
+	pub fn delete_signature_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DeleteCommitSignatureProtection<'a>> {
+		self.0.request(delete_commit_signature_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`GetStatusChecksProtection`].
+	pub fn status_checks_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetStatusChecksProtection<'a>> {
+		self.0.request(get_status_checks_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`UpdateStatusCheckProtection`].
+	pub fn update_status_checks_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, UpdateStatusCheckProtection<'a>> {
+		self.0.request(update_status_check_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`RemoveStatusCheckProtection`].
+	pub fn remove_status_checks_protection(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, RemoveStatusCheckProtection<'a>> {
+		self.0.request(remove_status_check_protection(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`GetAccessRestrictions`].
+	pub fn restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetAccessRestrictions<'a>> {
+		self.0.request(get_access_restrictions(self.0.owner.clone(), self.0.repo.clone(), branch))
+	}
+
+	/// [`DeleteAccessRestrictions`].
+	pub fn delete_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DeleteAccessRestrictions<'a>> {
+		self.0.request(delete_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`GetAppsWithAccessToTheProtectedBranch`].
+	pub fn app_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetAppsWithAccessToTheProtectedBranch<'a>> {
+		self.0.request(get_apps_with_access_to_the_protected_branch(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`AddAppAccessRestrictions`].
+	pub fn add_app_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		apps: impl Into<List<'a>>,
+	) -> Request<'c, AddAppAccessRestrictions<'a>> {
+		self.0.request(add_app_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			apps,
+		))
+	}
+
+	/// [`SetAppAccessRestrictions`].
+	pub fn set_app_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		apps: impl Into<List<'a>>,
+	) -> Request<'c, SetAppAccessRestrictions<'a>> {
+		self.0.request(set_app_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			apps,
+		))
+	}
+
+	/// [`RemoveAppAccessRestrictions`].
+	pub fn remove_app_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		apps: impl Into<List<'a>>,
+	) -> Request<'c, RemoveAppAccessRestrictions<'a>> {
+		self.0.request(remove_app_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			apps,
+		))
+	}
+
+	/// [`GetTeamsWithAccessToTheProtectedBranch`].
+	pub fn team_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetTeamsWithAccessToTheProtectedBranch<'a>> {
+		self.0.request(get_teams_with_access_to_the_protected_branch(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`AddTeamAccessRestrictions`].
+	pub fn add_team_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		teams: impl Into<List<'a>>,
+	) -> Request<'c, AddTeamAccessRestrictions<'a>> {
+		self.0.request(add_team_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			teams,
+		))
+	}
+
+	/// [`SetTeamAccessRestrictions`].
+	pub fn set_team_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		teams: impl Into<List<'a>>,
+	) -> Request<'c, SetTeamAccessRestrictions<'a>> {
+		self.0.request(set_team_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			teams,
+		))
+	}
+
+	/// [`RemoveTeamAccessRestrictions`].
+	pub fn remove_team_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		teams: impl Into<List<'a>>,
+	) -> Request<'c, RemoveTeamAccessRestrictions<'a>> {
+		self.0.request(remove_team_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			teams,
+		))
+	}
+
+	/// [`GetUsersWithAccessToTheProtectedBranch`].
+	pub fn user_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetUsersWithAccessToTheProtectedBranch<'a>> {
+		self.0.request(get_users_with_access_to_the_protected_branch(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`AddUserAccessRestrictions`].
+	pub fn add_user_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		users: impl Into<List<'a>>,
+	) -> Request<'c, AddUserAccessRestrictions<'a>> {
+		self.0.request(add_user_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			users,
+		))
+	}
+
+	/// [`SetUserAccessRestrictions`].
+	pub fn set_user_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		users: impl Into<List<'a>>,
+	) -> Request<'c, SetUserAccessRestrictions<'a>> {
+		self.0.request(set_user_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			users,
+		))
+	}
+
+	/// [`RemoveUserAccessRestrictions`].
+	pub fn remove_user_restrictions(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		users: impl Into<List<'a>>,
+	) -> Request<'c, RemoveUserAccessRestrictions<'a>> {
+		self.0.request(remove_user_access_restrictions(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+			users,
+		))
+	}
+
+	/// [`RenameABranch`].
+	pub fn rename(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+		new_name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, RenameABranch<'a>> {
+		self.0.request(rename_a_branch(self.0.owner.clone(), self.0.repo.clone(), branch, new_name))
+	}
+
+	/// [`SyncAForkBranchWithTheUpstreamRepository`].
+	pub fn sync_with_upstream(
+		&self,
+		branch: impl Into<Cow<'a, str>>,
+	) -> Request<'c, SyncAForkBranchWithTheUpstreamRepository<'a>> {
+		self.0.request(sync_a_fork_branch_with_the_upstream_repository(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			branch,
+		))
+	}
+
+	/// [`MergeABranch`].
+	pub fn merge(
+		&self,
+		base: impl Into<Cow<'a, str>>,
+		head: impl Into<Cow<'a, str>>,
+	) -> Request<'c, MergeABranch<'a>> {
+		self.0.request(merge_a_branch(self.0.owner.clone(), self.0.repo.clone(), base, head))
+	}
+}
+
+/// Handle of a repository's commits.
+#[derive(Debug, Clone)]
+pub struct RepoCommits<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoCommits<'c, 'a> {
+	/// [`ListCommits`].
+	pub fn list(&self) -> Request<'c, ListCommits<'a>> {
+		self.0.request(list_commits(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`GetACommit`].
+	pub fn get(&self, r#ref: impl Into<Cow<'a, str>>) -> Request<'c, GetACommit<'a>> {
+		self.0.request(get_a_commit(self.0.owner.clone(), self.0.repo.clone(), r#ref))
+	}
+
+	/// [`CompareTwoCommits`], `basehead` is in the form of `BASE...HEAD`.
+	pub fn compare(&self, basehead: impl Into<Cow<'a, str>>) -> Request<'c, CompareTwoCommits<'a>> {
+		self.0.request(compare_two_commits(self.0.owner.clone(), self.0.repo.clone(), basehead))
+	}
+
+	/// [`ListBranchesForHeadCommit`].
+	pub fn branches_where_head(
+		&self,
+		commit_sha: impl Into<Cow<'a, str>>,
+	) -> Request<'c, ListBranchesForHeadCommit<'a>> {
+		self.0.request(list_branches_for_head_commit(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			commit_sha,
+		))
+	}
+
+	/// [`ListPullRequestsAssociatedWithACommit`].
+	pub fn pulls(
+		&self,
+		commit_sha: impl Into<Cow<'a, str>>,
+	) -> Request<'c, ListPullRequestsAssociatedWithACommit<'a>> {
+		self.0.request(list_pull_requests_associated_with_a_commit(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			commit_sha,
+		))
+	}
+}
+
+/// Handle of a repository's contents.
+#[derive(Debug, Clone)]
+pub struct RepoContents<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoContents<'c, 'a> {
+	/// [`GetRepositoryContent`].
+	pub fn get(&self, path: impl Into<Cow<'a, str>>) -> Request<'c, GetRepositoryContent<'a>> {
+		self.0.request(get_repository_content(self.0.owner.clone(), self.0.repo.clone(), path))
+	}
+
+	/// [`CreateOrUpdateFileContents`].
+	pub fn put(
+		&self,
+		path: impl Into<Cow<'a, str>>,
+		message: impl Into<Cow<'a, str>>,
+		content: impl Into<Base64>,
+	) -> Request<'c, CreateOrUpdateFileContents<'a>> {
+		self.0.request(create_or_update_file_contents(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			path,
+			message,
+			content,
+		))
+	}
+
+	/// [`DeleteAFile`].
+	pub fn delete(
+		&self,
+		path: impl Into<Cow<'a, str>>,
+		message: impl Into<Cow<'a, str>>,
+		sha: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DeleteAFile<'a>> {
+		self.0.request(delete_a_file(self.0.owner.clone(), self.0.repo.clone(), path, message, sha))
+	}
+
+	/// [`GetARepositoryReadme`].
+	pub fn readme(&self) -> Request<'c, GetARepositoryReadme<'a>> {
+		self.0.request(get_a_repository_readme(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`GetARepositoryReadmeForADirectory`].
+	pub fn readme_in(
+		&self,
+		dir: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetARepositoryReadmeForADirectory<'a>> {
+		self.0.request(get_a_repository_readme_for_a_directory(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			dir,
+		))
+	}
+}
+
+/// Handle of a repository's issues.
+#[derive(Debug, Clone)]
+pub struct RepoIssues<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoIssues<'c, 'a> {
+	/// [`ListRepositoryIssues`].
+	pub fn list(&self) -> Request<'c, ListRepositoryIssues<'a>> {
+		self.0.request(list_repository_issues(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`CreateAnIssue`].
+	pub fn create(&self, title: impl Into<Cow<'a, str>>) -> Request<'c, CreateAnIssue<'a>> {
+		self.0.request(create_an_issue(self.0.owner.clone(), self.0.repo.clone(), title))
+	}
+
+	/// [`GetAnIssue`].
+	pub fn get(&self, issue_number: u32) -> Request<'c, GetAnIssue<'a>> {
+		self.0.request(get_an_issue(self.0.owner.clone(), self.0.repo.clone(), issue_number))
+	}
+
+	/// [`UpdateAnIssue`].
+	pub fn update(&self, issue_number: u32) -> Request<'c, UpdateAnIssue<'a>> {
+		self.0.request(update_an_issue(self.0.owner.clone(), self.0.repo.clone(), issue_number))
+	}
+
+	/// [`LockAnIssue`].
+	pub fn lock(&self, issue_number: u32) -> Request<'c, LockAnIssue<'a>> {
+		self.0.request(lock_an_issue(self.0.owner.clone(), self.0.repo.clone(), issue_number))
+	}
+
+	/// [`UnlockAnIssue`].
+	pub fn unlock(&self, issue_number: u32) -> Request<'c, UnlockAnIssue<'a>> {
+		self.0.request(unlock_an_issue(self.0.owner.clone(), self.0.repo.clone(), issue_number))
+	}
+
+	/// [`ListAssignees`].
+	pub fn assignees(&self) -> Request<'c, ListAssignees<'a>> {
+		self.0.request(list_assignees(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`CheckIfAUserCanBeAssigned`], send it with [`Request::check`].
+	pub fn assignable(
+		&self,
+		assignee: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CheckIfAUserCanBeAssigned<'a>> {
+		self.0.request(check_if_a_user_can_be_assigned(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			assignee,
+		))
+	}
+
+	/// [`AddAssigneesToAnIssue`].
+	pub fn add_assignees(
+		&self,
+		issue_number: u32,
+		assignees: impl Into<List<'a>>,
+	) -> Request<'c, AddAssigneesToAnIssue<'a>> {
+		self.0.request(add_assignees_to_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			assignees,
+		))
+	}
+
+	/// [`RemoveAssigneesFromAnIssue`].
+	pub fn remove_assignees(
+		&self,
+		issue_number: u32,
+		assignees: impl Into<List<'a>>,
+	) -> Request<'c, RemoveAssigneesFromAnIssue<'a>> {
+		self.0.request(remove_assignees_from_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			assignees,
+		))
+	}
+
+	/// [`CheckIfAUserCanBeAssignedToAIssue`], send it with [`Request::check`].
+	pub fn assignable_to(
+		&self,
+		issue_number: u32,
+		assignee: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CheckIfAUserCanBeAssignedToAIssue<'a>> {
+		self.0.request(check_if_a_user_can_be_assigned_to_a_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			assignee,
+		))
+	}
+
+	/// [`ListIssueCommentsForARepository`].
+	pub fn list_comments(&self) -> Request<'c, ListIssueCommentsForARepository<'a>> {
+		self.0.request(list_issue_comments_for_a_repository(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+		))
+	}
+
+	/// [`GetAnIssueComment`].
+	pub fn get_comment(&self, comment_id: u64) -> Request<'c, GetAnIssueComment<'a>> {
+		self.0.request(get_an_issue_comment(self.0.owner.clone(), self.0.repo.clone(), comment_id))
+	}
+
+	/// [`UpdateAnIssueComment`].
+	pub fn update_comment(
+		&self,
+		comment_id: u64,
+		body: impl Into<Cow<'a, str>>,
+	) -> Request<'c, UpdateAnIssueComment<'a>> {
+		self.0.request(update_an_issue_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			body,
+		))
+	}
+
+	/// [`DeleteAnIssueComment`].
+	pub fn delete_comment(&self, comment_id: u64) -> Request<'c, DeleteAnIssueComment<'a>> {
+		self.0.request(delete_an_issue_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+		))
+	}
+
+	/// [`ListIssueComments`].
+	pub fn comments(&self, issue_number: u32) -> Request<'c, ListIssueComments<'a>> {
+		self.0.request(list_issue_comments(self.0.owner.clone(), self.0.repo.clone(), issue_number))
+	}
+
+	/// [`CreateAnIssueComment`].
+	pub fn create_comment(
+		&self,
+		issue_number: u32,
+		body: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateAnIssueComment<'a>> {
+		self.0.request(create_an_issue_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			body,
+		))
+	}
+
+	/// [`ListIssueEventsForARepository`].
+	pub fn list_events(&self) -> Request<'c, ListIssueEventsForARepository<'a>> {
+		self.0
+			.request(list_issue_events_for_a_repository(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`GetAnIssueEvent`].
+	pub fn get_event(&self, event_id: u64) -> Request<'c, GetAnIssueEvent<'a>> {
+		self.0.request(get_an_issue_event(self.0.owner.clone(), self.0.repo.clone(), event_id))
+	}
+
+	/// [`ListIssueEvents`].
+	pub fn events(&self, issue_number: u32) -> Request<'c, ListIssueEvents<'a>> {
+		self.0.request(list_issue_events(self.0.owner.clone(), self.0.repo.clone(), issue_number))
+	}
+
+	/// [`ListTimelineEventsForAnIssue`].
+	pub fn timeline(&self, issue_number: u32) -> Request<'c, ListTimelineEventsForAnIssue<'a>> {
+		self.0.request(list_timeline_events_for_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+		))
+	}
+
+	/// [`ListLabelsForAnIssue`].
+	pub fn labels(&self, issue_number: u32) -> Request<'c, ListLabelsForAnIssue<'a>> {
+		self.0.request(list_labels_for_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+		))
+	}
+
+	/// [`AddLabelsToAnIssue`].
+	pub fn add_labels(
+		&self,
+		issue_number: u32,
+		labels: impl Into<List<'a>>,
+	) -> Request<'c, AddLabelsToAnIssue<'a>> {
+		self.0.request(add_labels_to_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			labels,
+		))
+	}
+
+	/// [`SetLabelsForAnIssue`].
+	pub fn set_labels(
+		&self,
+		issue_number: u32,
+		labels: impl Into<List<'a>>,
+	) -> Request<'c, SetLabelsForAnIssue<'a>> {
+		self.0.request(set_labels_for_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			labels,
+		))
+	}
+
+	/// [`RemoveAllLabelsFromAnIssue`].
+	pub fn remove_labels(&self, issue_number: u32) -> Request<'c, RemoveAllLabelsFromAnIssue<'a>> {
+		self.0.request(remove_all_labels_from_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+		))
+	}
+
+	/// [`RemoveALabelFromAnIssue`].
+	pub fn remove_label(
+		&self,
+		issue_number: u32,
+		name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, RemoveALabelFromAnIssue<'a>> {
+		self.0.request(remove_a_label_from_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			name,
+		))
+	}
+}
+
+/// Handle of a repository's labels.
+#[derive(Debug, Clone)]
+pub struct RepoLabels<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoLabels<'c, 'a> {
+	/// [`ListLabelsForARepository`].
+	pub fn list(&self) -> Request<'c, ListLabelsForARepository<'a>> {
+		self.0.request(list_labels_for_a_repository(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`CreateALabel`].
+	pub fn create(&self, name: impl Into<Cow<'a, str>>) -> Request<'c, CreateALabel<'a>> {
+		self.0.request(create_a_label(self.0.owner.clone(), self.0.repo.clone(), name))
+	}
+
+	/// [`GetALabel`].
+	pub fn get(&self, name: impl Into<Cow<'a, str>>) -> Request<'c, GetALabel<'a>> {
+		self.0.request(get_a_label(self.0.owner.clone(), self.0.repo.clone(), name))
+	}
+
+	/// [`UpdateALabel`].
+	pub fn update(&self, name: impl Into<Cow<'a, str>>) -> Request<'c, UpdateALabel<'a>> {
+		self.0.request(update_a_label(self.0.owner.clone(), self.0.repo.clone(), name))
+	}
+
+	/// [`DeleteALabel`].
+	pub fn delete(&self, name: impl Into<Cow<'a, str>>) -> Request<'c, DeleteALabel<'a>> {
+		self.0.request(delete_a_label(self.0.owner.clone(), self.0.repo.clone(), name))
+	}
+
+	/// [`ListLabelsForIssuesInAMilestone`].
+	pub fn for_milestone(
+		&self,
+		milestone_number: u32,
+	) -> Request<'c, ListLabelsForIssuesInAMilestone<'a>> {
+		self.0.request(list_labels_for_issues_in_a_milestone(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			milestone_number,
+		))
+	}
+}
+
+/// Handle of a repository's milestones.
+#[derive(Debug, Clone)]
+pub struct RepoMilestones<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoMilestones<'c, 'a> {
+	/// [`ListMilestones`].
+	pub fn list(&self) -> Request<'c, ListMilestones<'a>> {
+		self.0.request(list_milestones(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`CreateAMilestone`].
+	pub fn create(&self, title: impl Into<Cow<'a, str>>) -> Request<'c, CreateAMilestone<'a>> {
+		self.0.request(create_a_milestone(self.0.owner.clone(), self.0.repo.clone(), title))
+	}
+
+	/// [`GetAMilestone`].
+	pub fn get(&self, milestone_number: u32) -> Request<'c, GetAMilestone<'a>> {
+		self.0.request(get_a_milestone(self.0.owner.clone(), self.0.repo.clone(), milestone_number))
+	}
+
+	/// [`UpdateAMilestone`].
+	pub fn update(&self, milestone_number: u32) -> Request<'c, UpdateAMilestone<'a>> {
+		self.0.request(update_a_milestone(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			milestone_number,
+		))
+	}
+
+	/// [`DeleteAMilestone`].
+	pub fn delete(&self, milestone_number: u32) -> Request<'c, DeleteAMilestone<'a>> {
+		self.0.request(delete_a_milestone(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			milestone_number,
+		))
+	}
+}
+
+/// Handle of a repository's pull requests.
+#[derive(Debug, Clone)]
+pub struct RepoPulls<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoPulls<'c, 'a> {
+	/// [`ListPullRequests`].
+	pub fn list(&self) -> Request<'c, ListPullRequests<'a>> {
+		self.0.request(list_pull_requests(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`CreateAPullRequest`], merging `head` into `base`.
+	pub fn create(
+		&self,
+		head: impl Into<Cow<'a, str>>,
+		base: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateAPullRequest<'a>> {
+		self.0.request(create_a_pull_request(self.0.owner.clone(), self.0.repo.clone(), head, base))
+	}
+
+	/// [`GetAPullRequest`].
+	pub fn get(&self, pull_number: u32) -> Request<'c, GetAPullRequest<'a>> {
+		self.0.request(get_a_pull_request(self.0.owner.clone(), self.0.repo.clone(), pull_number))
+	}
+
+	/// [`UpdateAPullRequest`].
+	pub fn update(&self, pull_number: u32) -> Request<'c, UpdateAPullRequest<'a>> {
+		self.0.request(update_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`ListCommitsOnAPullRequest`].
+	pub fn commits(&self, pull_number: u32) -> Request<'c, ListCommitsOnAPullRequest<'a>> {
+		self.0.request(list_commits_on_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`ListPullRequestsFiles`].
+	pub fn files(&self, pull_number: u32) -> Request<'c, ListPullRequestsFiles<'a>> {
+		self.0.request(list_pull_requests_files(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`CheckIfAPullRequestHasBeenMerged`], send it with [`Request::check`].
+	pub fn merged(&self, pull_number: u32) -> Request<'c, CheckIfAPullRequestHasBeenMerged<'a>> {
+		self.0.request(check_if_a_pull_request_has_been_merged(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`MergeAPullRequest`].
+	pub fn merge(&self, pull_number: u32) -> Request<'c, MergeAPullRequest<'a>> {
+		self.0.request(merge_a_pull_request(self.0.owner.clone(), self.0.repo.clone(), pull_number))
+	}
+
+	/// [`UpdateAPullRequestBranch`].
+	pub fn update_branch(&self, pull_number: u32) -> Request<'c, UpdateAPullRequestBranch<'a>> {
+		self.0.request(update_a_pull_request_branch(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`ListReviewCommentsInARepository`].
+	pub fn list_comments(&self) -> Request<'c, ListReviewCommentsInARepository<'a>> {
+		self.0.request(list_review_comments_in_a_repository(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+		))
+	}
+
+	/// [`GetAReviewCommentForAPullRequest`].
+	pub fn get_comment(
+		&self,
+		comment_id: u64,
+	) -> Request<'c, GetAReviewCommentForAPullRequest<'a>> {
+		self.0.request(get_a_review_comment_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+		))
+	}
+
+	/// [`UpdateAReviewCommentForAPullRequest`].
+	pub fn update_comment(
+		&self,
+		comment_id: u64,
+		body: impl Into<Cow<'a, str>>,
+	) -> Request<'c, UpdateAReviewCommentForAPullRequest<'a>> {
+		self.0.request(update_a_review_comment_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			body,
+		))
+	}
+
+	/// [`DeleteAReviewCommentForAPullRequest`].
+	pub fn delete_comment(
+		&self,
+		comment_id: u64,
+	) -> Request<'c, DeleteAReviewCommentForAPullRequest<'a>> {
+		self.0.request(delete_a_review_comment_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+		))
+	}
+
+	/// [`ListReviewCommentsOnAPullRequest`].
+	pub fn comments(&self, pull_number: u32) -> Request<'c, ListReviewCommentsOnAPullRequest<'a>> {
+		self.0.request(list_review_comments_on_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`CreateAReviewCommentForAPullRequest`].
+	pub fn create_comment(
+		&self,
+		pull_number: u32,
+		body: impl Into<Cow<'a, str>>,
+		commit_id: impl Into<Cow<'a, str>>,
+		path: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateAReviewCommentForAPullRequest<'a>> {
+		self.0.request(create_a_review_comment_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			body,
+			commit_id,
+			path,
+		))
+	}
+
+	/// [`CreateAReplyForAReviewComment`].
+	pub fn reply(
+		&self,
+		pull_number: u32,
+		comment_id: u64,
+		body: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateAReplyForAReviewComment<'a>> {
+		self.0.request(create_a_reply_for_a_review_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			comment_id,
+			body,
+		))
+	}
+
+	/// [`GetAllRequestedReviewersForAPullRequest`].
+	pub fn requested_reviewers(
+		&self,
+		pull_number: u32,
+	) -> Request<'c, GetAllRequestedReviewersForAPullRequest<'a>> {
+		self.0.request(get_all_requested_reviewers_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`RequestReviewersForAPullRequest`].
+	pub fn request_reviewers(
+		&self,
+		pull_number: u32,
+	) -> Request<'c, RequestReviewersForAPullRequest<'a>> {
+		self.0.request(request_reviewers_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`RemoveRequestedReviewersFromAPullRequest`].
+	pub fn remove_requested_reviewers(
+		&self,
+		pull_number: u32,
+		reviewers: impl Into<List<'a>>,
+	) -> Request<'c, RemoveRequestedReviewersFromAPullRequest<'a>> {
+		self.0.request(remove_requested_reviewers_from_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			reviewers,
+		))
+	}
+
+	/// [`ListReviewsForAPullRequest`].
+	pub fn reviews(&self, pull_number: u32) -> Request<'c, ListReviewsForAPullRequest<'a>> {
+		self.0.request(list_reviews_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`CreateAReviewForAPullRequest`].
+	pub fn create_review(&self, pull_number: u32) -> Request<'c, CreateAReviewForAPullRequest<'a>> {
+		self.0.request(create_a_review_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+		))
+	}
+
+	/// [`GetAReviewForAPullRequest`].
+	pub fn get_review(
+		&self,
+		pull_number: u32,
+		review_id: u64,
+	) -> Request<'c, GetAReviewForAPullRequest<'a>> {
+		self.0.request(get_a_review_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			review_id,
+		))
+	}
+
+	/// [`UpdateAReviewForAPullRequest`].
+	pub fn update_review(
+		&self,
+		pull_number: u32,
+		review_id: u64,
+		body: impl Into<Cow<'a, str>>,
+	) -> Request<'c, UpdateAReviewForAPullRequest<'a>> {
+		self.0.request(update_a_review_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			review_id,
+			body,
+		))
+	}
+
+	/// [`DeleteAPendingReviewForAPullRequest`].
+	pub fn delete_review(
+		&self,
+		pull_number: u32,
+		review_id: u64,
+	) -> Request<'c, DeleteAPendingReviewForAPullRequest<'a>> {
+		self.0.request(delete_a_pending_review_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			review_id,
+		))
+	}
+
+	/// [`ListCommentsForAPullRequestReview`].
+	pub fn review_comments(
+		&self,
+		pull_number: u32,
+		review_id: u64,
+	) -> Request<'c, ListCommentsForAPullRequestReview<'a>> {
+		self.0.request(list_comments_for_a_pull_request_review(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			review_id,
+		))
+	}
+
+	/// [`DismissAReviewForAPullRequest`].
+	pub fn dismiss_review(
+		&self,
+		pull_number: u32,
+		review_id: u64,
+		message: impl Into<Cow<'a, str>>,
+	) -> Request<'c, DismissAReviewForAPullRequest<'a>> {
+		self.0.request(dismiss_a_review_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			review_id,
+			message,
+		))
+	}
+
+	/// [`SubmitAReviewForAPullRequest`].
+	pub fn submit_review(
+		&self,
+		pull_number: u32,
+		review_id: u64,
+		event: ReviewEvent,
+	) -> Request<'c, SubmitAReviewForAPullRequest<'a>> {
+		self.0.request(submit_a_review_for_a_pull_request(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			pull_number,
+			review_id,
+			event,
+		))
+	}
+}
+
+/// Handle of the reactions to a repository's issues, comments and releases.
+#[derive(Debug, Clone)]
+pub struct RepoReactions<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoReactions<'c, 'a> {
+	/// [`ListReactionsForACommitComment`].
+	pub fn list_for_commit_comment(
+		&self,
+		comment_id: u64,
+	) -> Request<'c, ListReactionsForACommitComment<'a>> {
+		self.0.request(list_reactions_for_a_commit_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+		))
+	}
+
+	/// [`CreateReactionForACommitComment`].
+	pub fn create_for_commit_comment(
+		&self,
+		comment_id: u64,
+		content: Reaction,
+	) -> Request<'c, CreateReactionForACommitComment<'a>> {
+		self.0.request(create_reaction_for_a_commit_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			content,
+		))
+	}
+
+	/// [`DeleteACommitCommentReaction`].
+	pub fn delete_for_commit_comment(
+		&self,
+		comment_id: u64,
+		reaction_id: u64,
+	) -> Request<'c, DeleteACommitCommentReaction<'a>> {
+		self.0.request(delete_a_commit_comment_reaction(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			reaction_id,
+		))
+	}
+
+	/// [`ListReactionsForAnIssueComment`].
+	pub fn list_for_issue_comment(
+		&self,
+		comment_id: u64,
+	) -> Request<'c, ListReactionsForAnIssueComment<'a>> {
+		self.0.request(list_reactions_for_an_issue_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+		))
+	}
+
+	/// [`CreateReactionForAnIssueComment`].
+	pub fn create_for_issue_comment(
+		&self,
+		comment_id: u64,
+		content: Reaction,
+	) -> Request<'c, CreateReactionForAnIssueComment<'a>> {
+		self.0.request(create_reaction_for_an_issue_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			content,
+		))
+	}
+
+	/// [`DeleteAnIssueCommentReaction`].
+	pub fn delete_for_issue_comment(
+		&self,
+		comment_id: u64,
+		reaction_id: u64,
+	) -> Request<'c, DeleteAnIssueCommentReaction<'a>> {
+		self.0.request(delete_an_issue_comment_reaction(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			reaction_id,
+		))
+	}
+
+	/// [`ListReactionsForAnIssue`].
+	pub fn list_for_issue(&self, issue_number: u32) -> Request<'c, ListReactionsForAnIssue<'a>> {
+		self.0.request(list_reactions_for_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+		))
+	}
+
+	/// [`CreateReactionForAnIssue`].
+	pub fn create_for_issue(
+		&self,
+		issue_number: u32,
+		content: Reaction,
+	) -> Request<'c, CreateReactionForAnIssue<'a>> {
+		self.0.request(create_reaction_for_an_issue(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			content,
+		))
+	}
+
+	/// [`DeleteAnIssueReaction`].
+	pub fn delete_for_issue(
+		&self,
+		issue_number: u32,
+		reaction_id: u64,
+	) -> Request<'c, DeleteAnIssueReaction<'a>> {
+		self.0.request(delete_an_issue_reaction(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			issue_number,
+			reaction_id,
+		))
+	}
+
+	/// [`ListReactionsForAPullRequestReviewComment`].
+	pub fn list_for_review_comment(
+		&self,
+		comment_id: u64,
+	) -> Request<'c, ListReactionsForAPullRequestReviewComment<'a>> {
+		self.0.request(list_reactions_for_a_pull_request_review_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+		))
+	}
+
+	/// [`CreateReactionForAPullRequestReviewComment`].
+	pub fn create_for_review_comment(
+		&self,
+		comment_id: u64,
+		content: Reaction,
+	) -> Request<'c, CreateReactionForAPullRequestReviewComment<'a>> {
+		self.0.request(create_reaction_for_a_pull_request_review_comment(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			content,
+		))
+	}
+
+	/// [`DeleteAPullRequestCommentReaction`].
+	pub fn delete_for_review_comment(
+		&self,
+		comment_id: u64,
+		reaction_id: u64,
+	) -> Request<'c, DeleteAPullRequestCommentReaction<'a>> {
+		self.0.request(delete_a_pull_request_comment_reaction(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			comment_id,
+			reaction_id,
+		))
+	}
+
+	/// [`ListReactionsForARelease`].
+	pub fn list_for_release(&self, release_id: u64) -> Request<'c, ListReactionsForARelease<'a>> {
+		self.0.request(list_reactions_for_a_release(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			release_id,
+		))
+	}
+
+	/// [`CreateReactionForARelease`].
+	pub fn create_for_release(
+		&self,
+		release_id: u64,
+		content: Reaction,
+	) -> Request<'c, CreateReactionForARelease<'a>> {
+		self.0.request(create_reaction_for_a_release(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			release_id,
+			content,
+		))
+	}
+
+	/// [`DeleteAReleaseReaction`].
+	pub fn delete_for_release(
+		&self,
+		release_id: u64,
+		reaction_id: u64,
+	) -> Request<'c, DeleteAReleaseReaction<'a>> {
+		self.0.request(delete_a_release_reaction(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			release_id,
+			reaction_id,
+		))
+	}
+}
+
+/// Handle of a repository's releases.
+#[derive(Debug, Clone)]
+pub struct RepoReleases<'c, 'a>(Repo<'c, 'a>);
+impl<'c, 'a> RepoReleases<'c, 'a> {
+	/// [`ListReleases`].
+	pub fn list(&self) -> Request<'c, ListReleases<'a>> {
+		self.0.request(list_releases(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`CreateARelease`].
+	pub fn create(&self, tag_name: impl Into<Cow<'a, str>>) -> Request<'c, CreateARelease<'a>> {
+		self.0.request(create_a_release(self.0.owner.clone(), self.0.repo.clone(), tag_name))
+	}
+
+	/// [`GetAReleaseAsset`].
+	pub fn get_asset(&self, asset_id: u64) -> Request<'c, GetAReleaseAsset<'a>> {
+		self.0.request(get_a_release_asset(self.0.owner.clone(), self.0.repo.clone(), asset_id))
+	}
+
+	/// [`UpdateAReleaseAsset`].
+	pub fn update_asset(&self, asset_id: u64) -> Request<'c, UpdateAReleaseAsset<'a>> {
+		self.0.request(update_a_release_asset(self.0.owner.clone(), self.0.repo.clone(), asset_id))
+	}
+
+	/// [`DeleteAReleaseAsset`].
+	pub fn delete_asset(&self, asset_id: u64) -> Request<'c, DeleteAReleaseAsset<'a>> {
+		self.0.request(delete_a_release_asset(self.0.owner.clone(), self.0.repo.clone(), asset_id))
+	}
+
+	/// [`GenerateReleaseNotesContentForARelease`].
+	pub fn generate_notes(
+		&self,
+		tag_name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GenerateReleaseNotesContentForARelease<'a>> {
+		self.0.request(generate_release_notes_content_for_a_release(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			tag_name,
+		))
+	}
+
+	/// [`GetTheLatestRelease`].
+	pub fn latest(&self) -> Request<'c, GetTheLatestRelease<'a>> {
+		self.0.request(get_the_latest_release(self.0.owner.clone(), self.0.repo.clone()))
+	}
+
+	/// [`GetAReleaseByTagName`].
+	pub fn get_by_tag(
+		&self,
+		tag: impl Into<Cow<'a, str>>,
+	) -> Request<'c, GetAReleaseByTagName<'a>> {
+		self.0.request(get_a_release_by_tag_name(self.0.owner.clone(), self.0.repo.clone(), tag))
+	}
+
+	/// [`GetARelease`].
+	pub fn get(&self, release_id: u64) -> Request<'c, GetARelease<'a>> {
+		self.0.request(get_a_release(self.0.owner.clone(), self.0.repo.clone(), release_id))
+	}
+
+	/// [`UpdateARelease`].
+	pub fn update(&self, release_id: u64) -> Request<'c, UpdateARelease<'a>> {
+		self.0.request(update_a_release(self.0.owner.clone(), self.0.repo.clone(), release_id))
+	}
+
+	/// [`DeleteARelease`].
+	pub fn delete(&self, release_id: u64) -> Request<'c, DeleteARelease<'a>> {
+		self.0.request(delete_a_release(self.0.owner.clone(), self.0.repo.clone(), release_id))
+	}
+
+	/// [`ListReleaseAssets`].
+	pub fn assets(&self, release_id: u64) -> Request<'c, ListReleaseAssets<'a>> {
+		self.0.request(list_release_assets(self.0.owner.clone(), self.0.repo.clone(), release_id))
+	}
+
+	/// [`UploadAReleaseAsset`], send it with [`Request::upload`].
+	pub fn upload_asset(
+		&self,
+		release_id: u64,
+		name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, UploadAReleaseAsset<'a>> {
+		self.0.request(upload_a_release_asset(
+			self.0.owner.clone(),
+			self.0.repo.clone(),
+			release_id,
+			name,
+		))
+	}
+}
+
+/// Handle of an organization.
+#[derive(Debug, Clone)]
+pub struct Org<'c, 'a> {
+	pub(super) client: &'c Client,
+	pub(super) org: Cow<'a, str>,
+}
+impl<'c, 'a> Org<'c, 'a> {
+	/// Handle of the organization's issues.
+	pub fn issues(&self) -> OrgIssues<'c, 'a> {
+		OrgIssues(self.clone())
+	}
+
+	/// Handle of the organization's repositories.
+	pub fn repos(&self) -> OrgRepos<'c, 'a> {
+		OrgRepos(self.clone())
+	}
+}
+
+/// Handle of an organization's issues.
+#[derive(Debug, Clone)]
+pub struct OrgIssues<'c, 'a>(Org<'c, 'a>);
+impl<'c, 'a> OrgIssues<'c, 'a> {
+	/// [`ListOrganizationIssuesAssignedToTheAuthenticatedUser`].
+	pub fn list(&self) -> Request<'c, ListOrganizationIssuesAssignedToTheAuthenticatedUser<'a>> {
+		Request::new(
+			self.0.client,
+			list_organization_issues_assigned_to_the_authenticated_user(self.0.org.clone()),
+		)
+	}
+}
+
+/// Handle of an organization's repositories.
+#[derive(Debug, Clone)]
+pub struct OrgRepos<'c, 'a>(Org<'c, 'a>);
+impl<'c, 'a> OrgRepos<'c, 'a> {
+	/// [`ListOrganizationRepositories`].
+	pub fn list(&self) -> Request<'c, ListOrganizationRepositories<'a>> {
+		Request::new(self.0.client, list_organization_repositories(self.0.org.clone()))
+	}
+
+	/// [`CreateAnOrganizationRepository`].
+	pub fn create(
+		&self,
+		name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateAnOrganizationRepository<'a>> {
+		Request::new(self.0.client, create_an_organization_repository(self.0.org.clone(), name))
+	}
+}
+
+/// Handle of a user.
+#[derive(Debug, Clone)]
+pub struct User<'c, 'a> {
+	pub(super) client: &'c Client,
+	pub(super) username: Cow<'a, str>,
+}
+impl<'c, 'a> User<'c, 'a> {
+	/// [`ListRepositoriesForAUser`].
+	pub fn repos(&self) -> Request<'c, ListRepositoriesForAUser<'a>> {
+		Request::new(self.client, list_repositories_for_a_user(self.username.clone()))
+	}
+}
+
+/// Handle of the authenticated user.
+#[derive(Debug, Clone)]
+pub struct Me<'c> {
+	pub(super) client: &'c Client,
+}
+impl<'c> Me<'c> {
+	/// Handle of the authenticated user's issues.
+	pub fn issues(&self) -> MeIssues<'c> {
+		MeIssues(self.clone())
+	}
+
+	/// Handle of the authenticated user's repositories.
+	pub fn repos(&self) -> MeRepos<'c> {
+		MeRepos(self.clone())
+	}
+}
+
+/// Handle of the authenticated user's issues.
+#[derive(Debug, Clone)]
+pub struct MeIssues<'c>(Me<'c>);
+impl<'c> MeIssues<'c> {
+	/// [`ListIssuesAssignedToTheAuthenticatedUser`], across all the visible repositories.
+	pub fn list<'a>(&self) -> Request<'c, ListIssuesAssignedToTheAuthenticatedUser<'a>> {
+		Request::new(self.0.client, list_issues_assigned_to_the_authenticated_user())
+	}
+
+	/// [`ListUserAccountIssuesAssignedToTheAuthenticatedUser`], across the owned and member
+	/// repositories.
+	pub fn list_owned<'a>(
+		&self,
+	) -> Request<'c, ListUserAccountIssuesAssignedToTheAuthenticatedUser<'a>> {
+		Request::new(self.0.client, list_user_account_issues_assigned_to_the_authenticated_user())
+	}
+}
+
+/// Handle of the authenticated user's repositories.
+#[derive(Debug, Clone)]
+pub struct MeRepos<'c>(Me<'c>);
+impl<'c> MeRepos<'c> {
+	/// [`ListRepositoriesForTheAuthenticatedUser`].
+	pub fn list<'a>(&self) -> Request<'c, ListRepositoriesForTheAuthenticatedUser<'a>> {
+		Request::new(self.0.client, list_repositories_for_the_authenticated_user())
+	}
+
+	/// [`CreateARepositoryForTheAuthenticatedUser`].
+	pub fn create<'a>(
+		&self,
+		name: impl Into<Cow<'a, str>>,
+	) -> Request<'c, CreateARepositoryForTheAuthenticatedUser<'a>> {
+		Request::new(self.0.client, create_a_repository_for_the_authenticated_user(name))
+	}
+}
@@ -0,0 +1,424 @@
+//! GitHub REST API client.
+//!
+//! Requests could be sent directly through [`Client::send`], or built from the resource handles:
+//! ```no_run
+//! # async fn example() -> Result<(), githuber::error::Error> {
+//! use githuber::{api::param::State, client::Client};
+//!
+//! let client = Client::new().token("<GITHUB_TOKEN>");
+//! let repo = client.repo("hack-ink", "githuber");
+//! let issues = repo.issues().list().state(State::Open).send().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The handles are thin wrappers of the request structs, the parameters are set with the same
+//! setters.
+
+#[cfg(test)] mod test;
+
+mod handle;
+pub use handle::*;
+
+// std
+use std::{borrow::Cow, io, path::Path};
+// crates.io
+use reqwest::{
+	header::{
+		HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT,
+	},
+	Body, RequestBuilder, StatusCode,
+};
+use serde_json::Value;
+use tokio::{
+	fs::File,
+	io::{AsyncRead, AsyncWrite, AsyncWriteExt},
+};
+use tokio_util::io::ReaderStream;
+// hack-ink
+use crate::{
+	api::repos::{list_public_repositories, ListPublicRepositories},
+	prelude::*,
+};
+
+/// GitHub REST API client.
+#[derive(Debug, Clone, Default)]
+pub struct Client {
+	http: reqwest::Client,
+	token: Option<String>,
+}
+impl Client {
+	/// Build an unauthenticated [`Client`].
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Authenticate the requests with a token.
+	pub fn token(mut self, token: impl Into<String>) -> Self {
+		self.token = Some(token.into());
+
+		self
+	}
+
+	/// Use a custom [`reqwest::Client`].
+	pub fn http(mut self, http: reqwest::Client) -> Self {
+		self.http = http;
+
+		self
+	}
+
+	/// Validate and send the request.
+	///
+	/// Payload parameters are sent as the query string for `GET` requests and as a JSON body for
+	/// the others, a `DELETE` request without any of them has no body. An empty response is
+	/// returned as [`Value::Null`].
+	///
+	/// [`BinaryBody`] APIs are rejected at compile time, use [`Client::upload`] instead:
+	/// ```compile_fail
+	/// use githuber::{api::releases::*, client::*};
+	///
+	/// let _ = Client::new().send(&upload_a_release_asset("hack-ink", "githuber", 1, "a.txt"));
+	/// ```
+	pub async fn send<R>(&self, request: &R) -> Result<Value, Error>
+	where
+		R: ApiExt,
+	{
+		const { assert!(!R::METADATA.binary_body, "use `Client::upload` to send a binary body") };
+
+		respond(self.build(request, None)?).await
+	}
+
+	/// Validate and send the request with a binary body.
+	///
+	/// Payload parameters are sent as the query string, the body is streamed with its own
+	/// `Content-Type` and `Content-Length`.
+	///
+	/// Only [`BinaryBody`] APIs could be uploaded:
+	/// ```compile_fail
+	/// # async fn example() {
+	/// use githuber::{api::releases::*, client::*};
+	///
+	/// let upload = Upload::bytes("githuber", "text/plain");
+	///
+	/// Client::new().upload(&list_releases("hack-ink", "githuber"), upload).await;
+	/// # }
+	/// ```
+	pub async fn upload<R>(&self, request: &R, upload: Upload) -> Result<Value, Error>
+	where
+		R: BinaryBody,
+	{
+		respond(self.build(request, Some(upload))?).await
+	}
+
+	/// Validate the request and stream the binary response into the writer.
+	///
+	/// The redirection to the storage is followed. `progress` is called after each chunk with the
+	/// downloaded bytes and the total bytes, if the server tells. Returns the downloaded bytes.
+	pub async fn download<R, W>(
+		&self,
+		request: &R,
+		writer: &mut W,
+		mut progress: impl FnMut(u64, Option<u64>),
+	) -> Result<u64, Error>
+	where
+		R: BinaryResponse,
+		W: Unpin + AsyncWrite,
+	{
+		let mut response = self.build(request, None)?.send().await?;
+		let status = response.status();
+
+		if !status.is_success() {
+			return Err(error(status, &response.bytes().await?));
+		}
+
+		let total = response.content_length();
+		let mut downloaded = 0;
+
+		while let Some(chunk) = response.chunk().await? {
+			writer.write_all(&chunk).await?;
+
+			downloaded += chunk.len() as u64;
+
+			progress(downloaded, total);
+		}
+
+		writer.flush().await?;
+
+		Ok(downloaded)
+	}
+
+	/// Validate the request and stream the binary response into a file, see [`Client::download`].
+	pub async fn download_file<R>(
+		&self,
+		request: &R,
+		path: impl AsRef<Path>,
+		progress: impl FnMut(u64, Option<u64>),
+	) -> Result<u64, Error>
+	where
+		R: BinaryResponse,
+	{
+		self.download(request, &mut File::create(path).await?, progress).await
+	}
+
+	/// Validate and send the check request.
+	///
+	/// `204` is returned as `true` and `404` as `false`. Note that GitHub also responds `404` if
+	/// the resource isn't visible to the client.
+	pub async fn check<R>(&self, request: &R) -> Result<bool, Error>
+	where
+		R: BooleanResponse,
+	{
+		let response = self.build(request, None)?.send().await?;
+
+		match response.status() {
+			StatusCode::NOT_FOUND => Ok(false),
+			status if status.is_success() => Ok(true),
+			status => Err(error(status, &response.bytes().await?)),
+		}
+	}
+
+	/// Validate and build the request as it's sent.
+	///
+	/// Payload parameters are sent as the query string for `GET` requests and the ones with a
+	/// binary body, and as a JSON body for the others.
+	pub(crate) fn build<R>(
+		&self,
+		request: &R,
+		upload: Option<Upload>,
+	) -> Result<RequestBuilder, Error>
+	where
+		R: ApiExt,
+	{
+		request.validate()?;
+
+		let method = match R::METHOD {
+			Method::Delete => reqwest::Method::DELETE,
+			Method::Get => reqwest::Method::GET,
+			Method::Patch => reqwest::Method::PATCH,
+			Method::Post => reqwest::Method::POST,
+			Method::Put => reqwest::Method::PUT,
+		};
+		// Replace the defaults, e.g. `Accept` with a media type.
+		let headers = request
+			.headers()
+			.into_iter()
+			.map(|(k, v)| {
+				Ok((
+					HeaderName::try_from(k).map_err(|e| Error::Request(e.to_string()))?,
+					HeaderValue::try_from(v).map_err(|e| Error::Request(e.to_string()))?,
+				))
+			})
+			.collect::<Result<HeaderMap, Error>>()?;
+		let builder = self
+			.http
+			.request(method, request.api())
+			.header(ACCEPT, R::ACCEPT)
+			.header(USER_AGENT, concat!("GitHuber-", env!("CARGO_PKG_VERSION")))
+			.headers(headers);
+		let builder = match &self.token {
+			Some(token) => builder.bearer_auth(token),
+			None => builder,
+		};
+		let payload_params = request.payload_params();
+
+		Ok(match (R::METHOD, upload) {
+			(_, Some(upload)) => builder
+				.query(&query_pairs(payload_params))
+				.header(CONTENT_TYPE, upload.content_type)
+				.header(CONTENT_LENGTH, upload.content_length)
+				.body(upload.body),
+			_ if R::METADATA.binary_body =>
+				return Err(Error::Request("expect a binary body to upload".into())),
+			(Method::Get, None) => builder.query(&query_pairs(payload_params)),
+			(Method::Delete, None) if payload_params.is_empty() => builder,
+			(_, None) => builder.json(
+				&payload_params
+					.into_iter()
+					.map(|(k, v)| (k.to_owned(), json(v)))
+					.collect::<serde_json::Map<_, _>>(),
+			),
+		})
+	}
+
+	/// Handle of a repository.
+	pub fn repo<'a>(
+		&self,
+		owner: impl Into<Cow<'a, str>>,
+		repo: impl Into<Cow<'a, str>>,
+	) -> Repo<'_, 'a> {
+		Repo { client: self, owner: owner.into(), repo: repo.into() }
+	}
+
+	/// Handle of an organization.
+	pub fn org<'a>(&self, org: impl Into<Cow<'a, str>>) -> Org<'_, 'a> {
+		Org { client: self, org: org.into() }
+	}
+
+	/// Handle of a user.
+	pub fn user<'a>(&self, username: impl Into<Cow<'a, str>>) -> User<'_, 'a> {
+		User { client: self, username: username.into() }
+	}
+
+	/// Handle of the authenticated user.
+	pub fn me(&self) -> Me<'_> {
+		Me { client: self }
+	}
+
+	/// [`ListPublicRepositories`], in the order that they were created.
+	pub fn public_repos(&self) -> Request<'_, ListPublicRepositories> {
+		Request::new(self, list_public_repositories())
+	}
+}
+
+/// A request bound to a [`Client`], ready to be sent.
+#[derive(Debug, Clone)]
+pub struct Request<'c, R> {
+	client: &'c Client,
+	request: R,
+}
+impl<'c, R> Request<'c, R>
+where
+	R: ApiExt,
+{
+	/// Bind the request to the client.
+	pub fn new(client: &'c Client, request: R) -> Self {
+		Self { client, request }
+	}
+
+	/// Modify the inner request.
+	pub fn map(mut self, f: impl FnOnce(R) -> R) -> Self {
+		self.request = f(self.request);
+
+		self
+	}
+
+	/// Get the inner request.
+	pub fn into_inner(self) -> R {
+		self.request
+	}
+
+	/// Validate and send the request, see [`Client::send`].
+	pub async fn send(self) -> Result<Value, Error> {
+		self.client.send(&self.request).await
+	}
+}
+impl<R> Request<'_, R>
+where
+	R: BinaryBody,
+{
+	/// Validate and upload the body, see [`Client::upload`].
+	pub async fn upload(self, upload: Upload) -> Result<Value, Error> {
+		self.client.upload(&self.request, upload).await
+	}
+}
+impl<R> Request<'_, R>
+where
+	R: BinaryResponse,
+{
+	/// Validate and stream the response into the writer, see [`Client::download`].
+	pub async fn download<W>(
+		self,
+		writer: &mut W,
+		progress: impl FnMut(u64, Option<u64>),
+	) -> Result<u64, Error>
+	where
+		W: Unpin + AsyncWrite,
+	{
+		self.client.download(&self.request, writer, progress).await
+	}
+
+	/// Validate and stream the response into a file, see [`Client::download_file`].
+	pub async fn download_file(
+		self,
+		path: impl AsRef<Path>,
+		progress: impl FnMut(u64, Option<u64>),
+	) -> Result<u64, Error> {
+		self.client.download_file(&self.request, path, progress).await
+	}
+}
+
+impl<R> Request<'_, R>
+where
+	R: BooleanResponse,
+{
+	/// Validate and send the check request, see [`Client::check`].
+	pub async fn check(self) -> Result<bool, Error> {
+		self.client.check(&self.request).await
+	}
+}
+
+/// Binary body of an upload.
+#[derive(Debug)]
+pub struct Upload {
+	body: Body,
+	content_length: u64,
+	content_type: String,
+}
+impl Upload {
+	/// Upload the bytes.
+	pub fn bytes(bytes: impl Into<Vec<u8>>, content_type: impl Into<String>) -> Self {
+		let bytes = bytes.into();
+
+		Self {
+			content_length: bytes.len() as _,
+			body: bytes.into(),
+			content_type: content_type.into(),
+		}
+	}
+
+	/// Stream the file, without reading it into memory.
+	pub async fn file(path: impl AsRef<Path>, content_type: impl Into<String>) -> io::Result<Self> {
+		let file = File::open(path).await?;
+		let content_length = file.metadata().await?.len();
+
+		Ok(Self::reader(file, content_length, content_type))
+	}
+
+	/// Stream the reader, which must yield exactly `content_length` bytes.
+	pub fn reader<R>(reader: R, content_length: u64, content_type: impl Into<String>) -> Self
+	where
+		R: 'static + Send + Sync + AsyncRead,
+	{
+		Self {
+			body: Body::wrap_stream(ReaderStream::new(reader)),
+			content_length,
+			content_type: content_type.into(),
+		}
+	}
+}
+
+async fn respond(builder: RequestBuilder) -> Result<Value, Error> {
+	let response = builder.send().await?;
+	let status = response.status();
+	let body = response.bytes().await?;
+
+	if !status.is_success() {
+		return Err(error(status, &body));
+	}
+	if body.is_empty() || status == StatusCode::NO_CONTENT {
+		return Ok(Value::Null);
+	}
+
+	serde_json::from_slice(&body).map_err(|e| Error::Request(e.to_string()))
+}
+
+fn error(status: StatusCode, body: &[u8]) -> Error {
+	let body = serde_json::from_slice::<Value>(body).unwrap_or_default();
+
+	Error::Response {
+		status: status.as_u16(),
+		message: body["message"].as_str().unwrap_or_default().into(),
+	}
+}
+
+fn json(param: Param) -> Value {
+	match param {
+		Param::Bool(v) => v.into(),
+		Param::Integer(v) => v.into(),
+		Param::UnsignedInteger(v) => v.into(),
+		Param::String(v) => v.into(),
+		Param::Array(v) => v.into_iter().map(json).collect(),
+		Param::Object(v) => v.into_iter().map(|(k, v)| (k, json(v))).collect(),
+		Param::Null => Value::Null,
+	}
+}
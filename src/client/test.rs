@@ -0,0 +1,137 @@
+use super::*;
+use crate::api::{commits::*, issues::*, repos::*};
+
+#[test]
+fn handles_should_work() {
+	let client = Client::new();
+	let repo = client.repo("hack-ink", "githuber");
+
+	assert_eq!(
+		repo.issues().list().state(State::Open).per_page(10).into_inner(),
+		list_repository_issues("hack-ink", "githuber").state(State::Open).per_page(10)
+	);
+	assert_eq!(
+		repo.commits().compare("main...dev").into_inner(),
+		compare_two_commits("hack-ink", "githuber", "main...dev")
+	);
+	assert_eq!(
+		repo.issues().update(1).state(IssueState::Closed).into_inner(),
+		update_an_issue("hack-ink", "githuber", 1).state(IssueState::Closed)
+	);
+	assert_eq!(
+		client.org("hack-ink").repos().create("githuber").private(true).into_inner(),
+		create_an_organization_repository("hack-ink", "githuber").private(true)
+	);
+	assert_eq!(
+		client.user("aurexav").repos().into_inner(),
+		list_repositories_for_a_user("aurexav")
+	);
+	assert_eq!(client.public_repos().since(1).into_inner(), list_public_repositories().since(1));
+
+	let since = String::from("2024-01-01T00:00:00Z");
+
+	assert_eq!(
+		client.me().issues().list().since(since.as_str()).into_inner(),
+		list_issues_assigned_to_the_authenticated_user().since(since.as_str())
+	);
+}
+
+#[tokio::test]
+async fn send_should_validate() {
+	assert_eq!(
+		Client::new().repo("hack-ink", "githuber").tags().per_page(200).send().await,
+		Err(Error::OutOfRange { param: "per_page", value: "200".into(), range: "1..=100" })
+	);
+}
+
+#[tokio::test]
+async fn upload_should_work() {
+	let client = Client::new();
+	let upload = Upload::bytes("githuber", "text/plain");
+
+	assert_eq!(upload.content_length, 8);
+	assert_eq!(
+		client.repo("hack-ink", "githuber").releases().upload_asset(1, "a.txt").into_inner(),
+		crate::api::releases::upload_a_release_asset("hack-ink", "githuber", 1, "a.txt")
+	);
+
+	let request = client
+		.build(
+			&crate::api::releases::upload_a_release_asset("hack-ink", "githuber", 1, "a b.txt")
+				.label("A"),
+			Some(upload),
+		)
+		.unwrap()
+		.build()
+		.unwrap();
+
+	assert_eq!(
+		request.url().as_str(),
+		"https://uploads.github.com/repos/hack-ink/githuber/releases/1/assets?name=a+b.txt&label=A"
+	);
+	assert_eq!(request.headers()[CONTENT_TYPE], "text/plain");
+	assert_eq!(request.headers()[CONTENT_LENGTH], "8");
+	assert_eq!(request.body().and_then(|body| body.as_bytes()), Some(b"githuber".as_slice()));
+
+	let path = env!("CARGO_MANIFEST_DIR").to_owned() + "/Cargo.toml";
+	let upload = Upload::file(&path, "text/plain").await.unwrap();
+
+	assert_eq!(upload.content_length, std::fs::metadata(&path).unwrap().len());
+}
+
+#[test]
+fn download_handles_should_work() {
+	let client = Client::new();
+	let repo = client.repo("hack-ink", "githuber");
+
+	assert_eq!(
+		repo.tarball("main").into_inner().api(),
+		"https://api.github.com/repos/hack-ink/githuber/tarball/main"
+	);
+	assert_eq!(
+		repo.zipball("main").into_inner().api(),
+		"https://api.github.com/repos/hack-ink/githuber/zipball/main"
+	);
+	assert_eq!(
+		repo.artifact(1).into_inner().api(),
+		"https://api.github.com/repos/hack-ink/githuber/actions/artifacts/1/zip"
+	);
+}
+
+#[test]
+fn pulls_handle_should_work() {
+	let client = Client::new();
+	let pulls = client.repo("hack-ink", "githuber").pulls();
+
+	assert_eq!(
+		pulls.create("dev", "main").draft(true).into_inner(),
+		crate::api::pulls::create_a_pull_request("hack-ink", "githuber", "dev", "main").draft(true)
+	);
+	assert_eq!(
+		pulls.remove_requested_reviewers(1, ["aurexav"]).into_inner(),
+		crate::api::pulls::remove_requested_reviewers_from_a_pull_request(
+			"hack-ink",
+			"githuber",
+			1,
+			["aurexav"]
+		)
+	);
+}
+
+#[test]
+fn headers_should_replace_defaults() {
+	let request = Client::new()
+		.build(
+			&crate::api::issues::get_an_issue_comment("hack-ink", "githuber", 1)
+				.media_type(MediaType::Html),
+			None,
+		)
+		.unwrap()
+		.build()
+		.unwrap();
+
+	assert_eq!(
+		request.headers().get_all(ACCEPT).iter().collect::<Vec<_>>(),
+		["application/vnd.github.html+json"]
+	);
+}
@@ -0,0 +1,71 @@
+//! GitHuber errors.
+
+// std
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Main error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	/// A parameter's value is out of its accepted range.
+	OutOfRange {
+		/// Name of the parameter.
+		param: &'static str,
+		/// Given value.
+		value: String,
+		/// Accepted range.
+		range: &'static str,
+	},
+	/// Two parameters can't be used together.
+	Conflict {
+		/// Name of the parameter.
+		param: &'static str,
+		/// Name of the conflicting parameter.
+		conflicts_with: &'static str,
+	},
+	/// Failed to decode a base64 string.
+	Decode(String),
+	/// Failed to send the request or to read the response.
+	#[cfg(feature = "client")]
+	Request(String),
+	/// GitHub responded with an error.
+	#[cfg(feature = "client")]
+	Response {
+		/// HTTP status code.
+		status: u16,
+		/// GitHub's error message.
+		message: String,
+	},
+	/// Failed to write the downloaded content.
+	#[cfg(feature = "client")]
+	Io(String),
+}
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		match self {
+			Self::OutOfRange { param, value, range } =>
+				write!(f, "parameter `{param}` is out of range `{range}`, found `{value}`"),
+			Self::Conflict { param, conflicts_with } =>
+				write!(f, "parameter `{param}` can't be used together with `{conflicts_with}`"),
+			Self::Decode(e) => write!(f, "failed to decode the base64 string, {e}"),
+			#[cfg(feature = "client")]
+			Self::Request(e) => write!(f, "failed to request GitHub, {e}"),
+			#[cfg(feature = "client")]
+			Self::Response { status, message } => write!(f, "GitHub responded with `{status}`, {message}"),
+			#[cfg(feature = "client")]
+			Self::Io(e) => write!(f, "failed to write the download, {e}"),
+		}
+	}
+}
+impl std::error::Error for Error {}
+#[cfg(feature = "client")]
+impl From<reqwest::Error> for Error {
+	fn from(e: reqwest::Error) -> Self {
+		Self::Request(e.to_string())
+	}
+}
+#[cfg(feature = "client")]
+impl From<std::io::Error> for Error {
+	fn from(e: std::io::Error) -> Self {
+		Self::Io(e.to_string())
+	}
+}
@@ -7,7 +7,16 @@
 pub mod prelude {
 	//! GitHuber prelude.
 
-	pub use crate::api::{Api, ApiExt, Method};
+	pub use crate::{
+		api::{
+			endpoint::{EndpointMetadata, ParamMetadata},
+			param::*,
+			Api, ApiExt, BinaryBody, BinaryResponse, BooleanResponse, Method,
+		},
+		error::Error,
+	};
 }
 
 pub mod api;
+#[cfg(feature = "client")] pub mod client;
+pub mod error;
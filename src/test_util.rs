@@ -16,11 +16,15 @@ impl ApiClient {
 	where
 		R: ApiExt,
 	{
-		let response = self
-			.0
-			.get(request.api())
-			.header(ACCEPT, R::ACCEPT)
-			.query(&request.payload_params())
+		request.validate()?;
+
+		let response = request
+			.headers()
+			.into_iter()
+			.fold(self.0.get(request.api()).header(ACCEPT, R::ACCEPT), |request, (k, v)| {
+				request.header(k, v)
+			})
+			.query(&query_pairs(request.payload_params()))
 			.send()
 			.await?
 			.json::<Value>()
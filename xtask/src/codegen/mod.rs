@@ -0,0 +1,514 @@
+//! Generate APIs from the OpenAPI description in `xtask/spec`.
+//!
+//! Each allowlisted operation becomes an `#[api_impl::api]` struct, together with the
+//! `#[api_impl::param]` enums of its parameters and the models of its response. The code of a
+//! category is written to `src/api/<category>/generated.rs`.
+//!
+//! Then all the `#[api_impl::api]` structs, generated or not, are registered in
+//! `register_endpoints!`, see [`registry`].
+
+#[cfg(test)] mod test;
+
+pub mod registry;
+
+// std
+use std::{
+	collections::BTreeMap,
+	fmt::Write as _,
+	fs,
+	io::Write as _,
+	path::Path,
+	process::{Command, Stdio},
+};
+// crates.io
+use anyhow::{bail, Result};
+use convert_case::{Case, Casing};
+use serde_json::Value;
+// githuber
+use crate::{openapi::*, workspace_root};
+
+/// Path parameters which could span several segments and keep their slashes, e.g. a file path or
+/// a branch named `feature/x`.
+///
+/// GitHub's description doesn't tell them apart.
+const MULTI_SEGMENT_PARAMS: [&str; 5] = ["basehead", "branch", "commit_sha", "path", "ref"];
+/// Enums which are shared by all the categories, see `src/api/param.rs`.
+const SHARED_ENUMS: [(&str, &str, &[&str]); 2] =
+	[("direction", "Direction", &["asc", "desc"]), ("state", "State", &["open", "closed", "all"])];
+
+pub fn run() -> Result<()> {
+	let root = workspace_root();
+
+	for (category, code) in generate_all(&root)? {
+		let module = root.join("src/api").join(&category);
+
+		if !module.join("mod.rs").exists() {
+			bail!("module `api::{category}` doesn't exist, create it and declare `mod generated;`");
+		}
+
+		let path = module.join("generated.rs");
+
+		fs::write(&path, code)?;
+
+		println!("Generated {}", path.display());
+	}
+
+	let path = root.join(registry::PATH);
+
+	fs::write(&path, generate_registry(&root)?)?;
+
+	println!("Generated {}", path.display());
+
+	Ok(())
+}
+
+/// Generate and format the code of all the allowlisted operations, grouped by category.
+pub fn generate_all(root: &Path) -> Result<BTreeMap<String, String>> {
+	let spec = Spec::load(&root.join("xtask/spec/api.github.com.json"))?;
+	let allowlist = fs::read_to_string(root.join("xtask/spec/allowlist"))?;
+
+	generate(&spec, &parse_allowlist(&allowlist))?
+		.into_iter()
+		.map(|(category, code)| Ok((category, format(root, &code)?)))
+		.collect()
+}
+
+/// Generate and format the registry of all the `#[api_impl::api]` structs.
+pub fn generate_registry(root: &Path) -> Result<String> {
+	format(root, &registry::generate(&registry::collect(root)?))
+}
+
+/// Parse the allowlist, one `operationId` per line and `#` for comments.
+pub fn parse_allowlist(allowlist: &str) -> Vec<&str> {
+	allowlist.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')).collect()
+}
+
+/// Generate the unformatted code of the given operations, grouped by category.
+pub fn generate(spec: &Spec, allowlist: &[&str]) -> Result<BTreeMap<String, String>> {
+	let operations = spec.operations();
+	let mut categories = BTreeMap::<&str, Category>::new();
+
+	for id in allowlist {
+		let Some(operation) = operations.iter().find(|o| o.id == *id) else {
+			bail!("operation `{id}` doesn't exist in the OpenAPI description");
+		};
+
+		categories.entry(operation.category).or_default().request(spec, operation)?;
+	}
+
+	Ok(categories.into_iter().map(|(category, code)| (category.into(), code.render())).collect())
+}
+
+fn format(root: &Path, code: &str) -> Result<String> {
+	let mut rustfmt = Command::new("rustfmt")
+		.args(["--edition", "2021", "--config-path"])
+		.arg(root.join(".rustfmt.toml"))
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.spawn()?;
+
+	rustfmt.stdin.take().unwrap().write_all(code.as_bytes())?;
+
+	let output = rustfmt.wait_with_output()?;
+
+	if !output.status.success() {
+		bail!("failed to format the generated code");
+	}
+
+	Ok(String::from_utf8(output.stdout)?)
+}
+
+#[derive(Default)]
+struct Category {
+	requests: Vec<String>,
+	enums: BTreeMap<String, String>,
+	/// `None` while the model is being generated, to break the recursion.
+	models: BTreeMap<String, Option<String>>,
+}
+impl Category {
+	fn request(&mut self, spec: &Spec, operation: &Operation) -> Result<()> {
+		let name = pascal(operation.summary);
+		let mut fields = Vec::new();
+
+		for param in operation.path_params() {
+			let param_spec =
+				operation.parameters.iter().find(|p| p.location == "path" && p.name == param);
+			let ty = match param_spec.and_then(|p| p.schema["type"].as_str()) {
+				Some("integer") if param.ends_with("_id") => "u64",
+				Some("integer") => "u32",
+				_ => "&'a str",
+			};
+			let attr = if MULTI_SEGMENT_PARAMS.contains(&param) {
+				"#[path_param(multi_segment)]"
+			} else {
+				"#[path_param]"
+			};
+
+			fields.push(Field::new(param, ty.into()).attr(attr.into()));
+		}
+		for param in operation.parameters.iter().filter(|p| p.location != "path") {
+			let ty = self.param_ty(&name, param.name, param.schema)?;
+			let ty = if param.required { ty } else { format!("Option<{ty}>") };
+			let field = match param.location {
+				"header" =>
+					Field::new(param.name, ty).attr(format!("#[header_param({:?})]", param.name)),
+				_ if param.required =>
+					Field::new(param.name, ty).attr("#[payload_ess_param]".into()),
+				_ => Field::new(param.name, ty),
+			};
+
+			fields.push(field);
+		}
+		if let Some(body) = operation.body {
+			let required = strs(&body["required"]);
+
+			for (param, schema) in body["properties"].as_object().into_iter().flatten() {
+				let ty = self.param_ty(&name, param, spec.resolve(schema))?;
+				let field = if required.contains(&param.as_str()) {
+					Field::new(param, ty).attr("#[payload_ess_param]".into())
+				} else {
+					Field::new(param, format!("Option<{ty}>"))
+				};
+
+				fields.push(field);
+			}
+		}
+		if let Some(response) = operation.response {
+			self.model(
+				spec,
+				&format!("{name}Response"),
+				&format!("Response of [`{name}`]."),
+				response,
+			);
+		}
+
+		let lifetime = if fields.iter().any(|f| f.ty.contains("'a")) { "<'a>" } else { "" };
+		let mut code = String::new();
+
+		let mut properties = vec![
+			format!("category = {:?}", operation.category),
+			format!("method = {:?}", operation.method),
+			"accept = \"application/vnd.github+json\"".into(),
+			format!("uri = {:?}", uri(operation.path)),
+		];
+		let default_docs = format!(
+			"https://docs.github.com/en/rest/{0}/{0}#{1}",
+			operation.category,
+			name.to_case(Case::Kebab)
+		);
+
+		if !operation.docs.is_empty() && docs(operation.docs) != default_docs {
+			properties.push(format!("docs = {:?}", docs(operation.docs)));
+		}
+		if operation.deprecated {
+			properties.push("deprecated = \"deprecated by GitHub\"".into());
+		}
+		if let Some(base_uri) = operation.base_uri {
+			properties.push(format!("base_uri = {base_uri:?}"));
+		}
+		if operation.binary_body {
+			properties.push("body = \"binary\"".into());
+		}
+		if operation.binary_response {
+			properties.push("response = \"binary\"".into());
+		}
+		if operation.boolean_response {
+			properties.push("response = \"boolean\"".into());
+		}
+
+		writeln!(code, "#[api_impl::api]").unwrap();
+		writeln!(code, "#[properties(").unwrap();
+		writeln!(code, "\t{}", properties.join(",\n\t")).unwrap();
+		writeln!(code, ")]").unwrap();
+		writeln!(code, "pub struct {name}{lifetime} {{").unwrap();
+
+		for field in fields {
+			field.render(&mut code);
+		}
+
+		writeln!(code, "}}").unwrap();
+
+		self.requests.push(code);
+
+		Ok(())
+	}
+
+	/// Map a parameter's schema to a Rust type.
+	fn param_ty(&mut self, owner: &str, name: &str, schema: &Value) -> Result<String> {
+		let ty = match (name, schema["type"].as_str()) {
+			("per_page", _) => "u8",
+			("page", _) => "u16",
+			(_, Some("string")) if schema["enum"].is_array() =>
+				return Ok(self.param_enum(owner, name, &strs(&schema["enum"]))),
+			(_, Some("string")) if schema["format"] == "date-time" => "Timestamp",
+			(_, Some("string")) => "&'a str",
+			(_, Some("integer")) if name.ends_with("_id") => "u64",
+			(_, Some("integer")) => "u32",
+			(_, Some("boolean")) => "bool",
+			(_, Some("array")) if schema["items"]["type"] == "string" => "List<'a>",
+			_ => bail!(
+				"parameter `{name}` of `{owner}` has an unsupported schema, implement the API by hand"
+			),
+		};
+
+		Ok(ty.into())
+	}
+
+	fn param_enum(&mut self, owner: &str, name: &str, values: &[&str]) -> String {
+		if let Some((_, shared, _)) = SHARED_ENUMS.iter().find(|(shared_name, _, shared_values)| {
+			*shared_name == name
+				&& shared_values.len() == values.len()
+				&& shared_values.iter().all(|v| values.contains(v))
+		}) {
+			return (*shared).into();
+		}
+
+		let enum_name = format!("{owner}{}", pascal(name));
+		let mut code = String::new();
+
+		writeln!(code, "/// `{name}` of [`{owner}`].").unwrap();
+		writeln!(code, "#[api_impl::param]").unwrap();
+		writeln!(code, "pub enum {enum_name} {{").unwrap();
+
+		for value in values {
+			let variant = variant(value);
+
+			if variant.to_case(Case::Snake) != *value {
+				writeln!(code, "\t#[rename = {value:?}]").unwrap();
+			}
+
+			writeln!(code, "\t{variant},").unwrap();
+		}
+
+		writeln!(code, "}}").unwrap();
+
+		self.enums.insert(enum_name.clone(), code);
+
+		enum_name
+	}
+
+	/// Map a response schema to a Rust type, generating the models on the way.
+	///
+	/// Returns `None` if the schema isn't supported.
+	fn model(&mut self, spec: &Spec, hint: &str, doc: &str, schema: &Value) -> Option<String> {
+		if let Some(component) = schema.get("$ref").and_then(Value::as_str) {
+			let component = component.rsplit('/').next().unwrap_or_default();
+			let name = pascal(component);
+
+			match self.models.get(&name) {
+				Some(Some(_)) => return Some(name),
+				Some(None) => return Some(format!("Box<{name}>")),
+				None => (),
+			}
+
+			self.models.insert(name.clone(), None);
+
+			let resolved = spec.resolve(schema);
+			let code =
+				self.object(spec, &name, &format!("GitHub's `{component}` schema."), resolved);
+
+			self.models.insert(name.clone(), code);
+
+			return self.models[&name].as_ref().map(|_| name);
+		}
+		if let Some([schema]) = schema["allOf"].as_array().map(Vec::as_slice) {
+			return self.model(spec, hint, doc, schema);
+		}
+
+		let ty = match schema["type"].as_str()? {
+			"string" if schema["format"] == "date-time" => "Timestamp".into(),
+			"string" => "String".into(),
+			"integer" => "i64".into(),
+			"number" => "f64".into(),
+			"boolean" => "bool".into(),
+			"array" => format!("Vec<{}>", self.model(spec, hint, doc, &schema["items"])?),
+			"object" if schema["properties"].is_object() => {
+				let code = self.object(spec, hint, doc, schema)?;
+
+				self.models.insert(hint.into(), Some(code));
+
+				hint.into()
+			},
+			"object" if schema["additionalProperties"].is_object() => format!(
+				"std::collections::BTreeMap<String, {}>",
+				self.model(spec, hint, doc, &schema["additionalProperties"])?
+			),
+			_ => return None,
+		};
+
+		Some(ty)
+	}
+
+	fn object(&mut self, spec: &Spec, name: &str, doc: &str, schema: &Value) -> Option<String> {
+		let properties = schema["properties"].as_object()?;
+		let required = strs(&schema["required"]);
+		let mut code = String::new();
+
+		writeln!(code, "/// {doc}").unwrap();
+		writeln!(code, "#[derive(Debug, Clone, PartialEq)]").unwrap();
+		writeln!(
+			code,
+			"#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]"
+		)
+		.unwrap();
+		writeln!(code, "pub struct {name} {{").unwrap();
+
+		for (property, property_schema) in properties {
+			let hint = format!("{name}{}", pascal(property));
+			let property_doc = format!("`{property}` of [`{name}`].");
+			let Some(mut ty) = self.model(spec, &hint, &property_doc, property_schema) else {
+				writeln!(code, "\t// Skipped `{property}`, unsupported schema.").unwrap();
+
+				continue;
+			};
+
+			// IDs are unsigned, like the `_id` parameters.
+			if ty == "i64" && (property == "id" || property.ends_with("_id")) {
+				ty = "u64".into();
+			}
+			if !required.contains(&property.as_str())
+				|| spec.resolve(property_schema)["nullable"].as_bool().unwrap_or_default()
+			{
+				ty = format!("Option<{ty}>");
+			}
+
+			let field = ident(property);
+
+			writeln!(code, "\t#[allow(missing_docs)]").unwrap();
+
+			if field.trim_start_matches("r#") != property {
+				writeln!(code, "\t#[cfg_attr(feature = \"serde\", serde(rename = {property:?}))]")
+					.unwrap();
+			}
+
+			writeln!(code, "\tpub {field}: {ty},").unwrap();
+		}
+
+		writeln!(code, "}}").unwrap();
+
+		Some(code)
+	}
+
+	fn render(self) -> String {
+		let mut code = String::new();
+
+		writeln!(code, "//! Generated by `cargo xtask codegen` from GitHub's OpenAPI description.")
+			.unwrap();
+		writeln!(code, "//!").unwrap();
+		writeln!(
+			code,
+			"//! Don't edit it by hand, allowlist the operation in `xtask/spec/allowlist`."
+		)
+		.unwrap();
+		writeln!(code).unwrap();
+		writeln!(code, "// hack-ink").unwrap();
+		writeln!(code, "use crate::prelude::*;").unwrap();
+
+		for item in self
+			.requests
+			.into_iter()
+			.chain(self.enums.into_values())
+			.chain(self.models.into_values().flatten())
+		{
+			writeln!(code).unwrap();
+			code.push_str(&item);
+		}
+
+		code
+	}
+}
+
+struct Field {
+	attrs: Vec<String>,
+	/// Name of the parameter in the description.
+	param: String,
+	name: String,
+	ty: String,
+}
+impl Field {
+	fn new(param: &str, ty: String) -> Self {
+		Self { attrs: Vec::new(), param: param.into(), name: ident(param), ty }
+	}
+
+	fn attr(mut self, attr: String) -> Self {
+		self.attrs.push(attr);
+
+		self
+	}
+
+	fn render(self, code: &mut String) {
+		if self.name == "per_page" {
+			writeln!(code, "\t#[range(1..=100)]").unwrap();
+		}
+		if ["List<", "Timestamp"].iter().any(|ty| self.ty.contains(ty)) {
+			writeln!(code, "\t#[into]").unwrap();
+		}
+		// Headers are named by their attribute.
+		if self.name.trim_start_matches("r#") != self.param
+			&& !self.attrs.iter().any(|attr| attr.starts_with("#[header_param"))
+		{
+			writeln!(code, "\t#[rename = {:?}]", self.param).unwrap();
+		}
+		for attr in self.attrs {
+			writeln!(code, "\t{attr}").unwrap();
+		}
+
+		writeln!(code, "\tpub {}: {},", self.name, self.ty).unwrap();
+	}
+}
+
+fn strs(value: &Value) -> Vec<&str> {
+	value.as_array().into_iter().flatten().filter_map(Value::as_str).collect()
+}
+
+/// `/repos/{owner}/{repo}` to `/repos/{}/{}`.
+pub fn uri(path: &str) -> String {
+	path.split('{')
+		.enumerate()
+		.map(|(i, segment)| match (i, segment.split_once('}')) {
+			(0, _) | (_, None) => segment.to_owned(),
+			(_, Some((_, rest))) => format!("{{}}{rest}"),
+		})
+		.collect()
+}
+
+/// Documentation URLs of the description omit the locale, e.g. `https://docs.github.com/rest/..`.
+pub fn docs(url: &str) -> String {
+	match url.strip_prefix("https://docs.github.com/rest/") {
+		Some(rest) => format!("https://docs.github.com/en/rest/{rest}"),
+		None => url.into(),
+	}
+}
+
+pub fn pascal(s: &str) -> String {
+	s.replace(|c: char| !c.is_ascii_alphanumeric(), " ").to_case(Case::Pascal)
+}
+
+fn variant(value: &str) -> String {
+	let value = match value.chars().next() {
+		Some('+') => format!("plus {}", &value[1..]),
+		Some('-') => format!("minus {}", &value[1..]),
+		Some(c) if c.is_ascii_digit() => format!("v {value}"),
+		_ => value.to_owned(),
+	};
+
+	pascal(&value)
+}
+
+fn ident(name: &str) -> String {
+	let ident = match name {
+		"+1" => "plus_one".into(),
+		"-1" => "minus_one".into(),
+		_ if name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') =>
+			name.into(),
+		_ => name.replace(|c: char| !c.is_ascii_alphanumeric(), " ").to_case(Case::Snake),
+	};
+
+	match ident.as_str() {
+		// Raw identifiers can't be made of these.
+		"self" | "super" | "crate" => format!("{ident}_"),
+		"type" | "ref" | "move" | "match" | "mod" | "use" | "where" | "for" | "loop" =>
+			format!("r#{ident}"),
+		_ => ident,
+	}
+}
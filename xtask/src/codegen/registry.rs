@@ -0,0 +1,193 @@
+//! Generate the registry of `githuber::api::endpoint::Endpoint`.
+//!
+//! The module tree under `src/api` is walked from `src/api/mod.rs` and every `#[api_impl::api]`
+//! struct is registered in `register_endpoints!`, which is written to
+//! `src/api/endpoint/generated.rs`.
+
+// std
+use std::{
+	fmt::Write as _,
+	fs,
+	path::{Path, PathBuf},
+};
+// crates.io
+use anyhow::{bail, Context, Result};
+use syn::{Attribute, Item, Meta, Visibility};
+
+/// Path of the registry, relative to the workspace root.
+pub const PATH: &str = "src/api/endpoint/generated.rs";
+
+/// A registered `#[api_impl::api]` struct.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entry {
+	/// Path of the public module which exports it, relative to `crate::api`.
+	pub module: Vec<String>,
+	pub name: String,
+	pub lifetime: bool,
+	/// `#[cfg]`s of the struct and of its modules.
+	pub cfgs: Vec<String>,
+}
+
+/// Collect the `#[api_impl::api]` structs of the module tree under `src/api`.
+pub fn collect(root: &Path) -> Result<Vec<Entry>> {
+	let mut entries = Vec::new();
+
+	walk(root, &root.join("src/api/mod.rs"), &[], &[], &mut entries)?;
+
+	Ok(entries)
+}
+
+/// Generate the unformatted registry of the given structs.
+pub fn generate(entries: &[Entry]) -> String {
+	let mut modules = Vec::<String>::new();
+
+	for entry in entries {
+		let module = entry.module.join("::");
+
+		if !modules.contains(&module) {
+			modules.push(module);
+		}
+	}
+
+	modules.sort_unstable();
+
+	let mut code = String::new();
+
+	writeln!(code, "//! Generated by `cargo xtask codegen` from the `#[api_impl::api]` structs.")
+		.unwrap();
+	writeln!(code, "//!").unwrap();
+	writeln!(code, "//! Don't edit it by hand, it's regenerated along with the APIs.").unwrap();
+	writeln!(code).unwrap();
+	writeln!(code, "// hack-ink").unwrap();
+	writeln!(code, "use super::*;").unwrap();
+	writeln!(
+		code,
+		"use crate::api::{{{}}};",
+		modules.iter().map(|m| format!("{m}::*")).collect::<Vec<_>>().join(", ")
+	)
+	.unwrap();
+	writeln!(code).unwrap();
+	writeln!(code, "register_endpoints! {{").unwrap();
+
+	for entry in entries {
+		for cfg in &entry.cfgs {
+			writeln!(code, "\t{cfg}").unwrap();
+		}
+
+		writeln!(code, "\t{}{},", entry.name, if entry.lifetime { "<'a>" } else { "" }).unwrap();
+	}
+
+	writeln!(code, "}}").unwrap();
+
+	code
+}
+
+fn walk(
+	root: &Path,
+	path: &Path,
+	module: &[String],
+	cfgs: &[String],
+	entries: &mut Vec<Entry>,
+) -> Result<()> {
+	// The registry itself.
+	if path == root.join(PATH) {
+		return Ok(());
+	}
+
+	let code = fs::read_to_string(path).with_context(|| format!("failed to read {path:?}"))?;
+	let file = syn::parse_file(&code).with_context(|| format!("failed to parse {path:?}"))?;
+	// Children of `x/mod.rs` live in `x/`, children of `x.rs` live in `x/` too.
+	let dir = if path.ends_with("mod.rs") {
+		path.parent().unwrap().to_path_buf()
+	} else {
+		path.with_extension("")
+	};
+
+	walk_items(root, &dir, &file.items, module, cfgs, entries)
+}
+
+fn walk_items(
+	root: &Path,
+	dir: &Path,
+	items: &[Item],
+	module: &[String],
+	cfgs: &[String],
+	entries: &mut Vec<Entry>,
+) -> Result<()> {
+	for item in items {
+		match item {
+			Item::Mod(item) => {
+				if is_test(&item.attrs) {
+					continue;
+				}
+
+				let name = item.ident.to_string();
+				// Private modules are re-exported by their parents, e.g. `pub use generated::*;`.
+				let module = match item.vis {
+					Visibility::Public(_) =>
+						module.iter().cloned().chain([name.clone()]).collect::<Vec<_>>(),
+					_ => module.to_vec(),
+				};
+				let cfgs = cfgs.iter().cloned().chain(cfg_attrs(&item.attrs)).collect::<Vec<_>>();
+
+				if item.attrs.iter().any(|attr| attr.path().is_ident("path")) {
+					bail!("`#[path]` of `mod {name}` isn't supported");
+				}
+
+				match &item.content {
+					Some((_, content)) =>
+						walk_items(root, &dir.join(&name), content, &module, &cfgs, entries)?,
+					None => walk(root, &file(dir, &name)?, &module, &cfgs, entries)?,
+				}
+			},
+			Item::Struct(item) if item.attrs.iter().any(is_api) => entries.push(Entry {
+				module: module.to_vec(),
+				name: item.ident.to_string(),
+				lifetime: item.generics.lifetimes().next().is_some(),
+				cfgs: cfgs.iter().cloned().chain(cfg_attrs(&item.attrs)).collect(),
+			}),
+			_ => (),
+		}
+	}
+
+	Ok(())
+}
+
+fn file(dir: &Path, name: &str) -> Result<PathBuf> {
+	let file = dir.join(format!("{name}.rs"));
+
+	if file.exists() {
+		return Ok(file);
+	}
+
+	let file = dir.join(name).join("mod.rs");
+
+	if file.exists() {
+		return Ok(file);
+	}
+
+	bail!("file of `mod {name}` doesn't exist in {dir:?}")
+}
+
+fn is_api(attr: &Attribute) -> bool {
+	let segments = attr.path().segments.iter().map(|s| s.ident.to_string()).collect::<Vec<_>>();
+
+	segments == ["api_impl", "api"]
+}
+
+fn is_test(attrs: &[Attribute]) -> bool {
+	attrs.iter().any(|attr| {
+		attr.path().is_ident("cfg")
+			&& attr.parse_args::<syn::Ident>().is_ok_and(|ident| ident == "test")
+	})
+}
+
+fn cfg_attrs(attrs: &[Attribute]) -> impl Iterator<Item = String> + '_ {
+	attrs.iter().filter_map(|attr| match &attr.meta {
+		Meta::List(list) if list.path.is_ident("cfg") => Some(format!(
+			"#[cfg({})]",
+			list.tokens.to_string().replace(" (", "(").replace(" ,", ",")
+		)),
+		_ => None,
+	})
+}
@@ -0,0 +1,352 @@
+use super::*;
+
+#[test]
+fn generated_code_should_be_up_to_date() {
+	let root = workspace_root();
+
+	for (category, code) in generate_all(&root).unwrap() {
+		let path = root.join("src/api").join(&category).join("generated.rs");
+
+		assert_eq!(
+			fs::read_to_string(&path).unwrap_or_default(),
+			code,
+			"{} is outdated, run `cargo xtask codegen`",
+			path.display()
+		);
+	}
+}
+
+#[test]
+fn generated_registry_should_be_up_to_date() {
+	let root = workspace_root();
+	let path = root.join(registry::PATH);
+
+	assert_eq!(
+		fs::read_to_string(&path).unwrap_or_default(),
+		generate_registry(&root).unwrap(),
+		"{} is outdated, run `cargo xtask codegen`",
+		path.display()
+	);
+}
+
+#[test]
+fn registry_should_work() {
+	let root = std::env::temp_dir().join(format!("githuber-registry-{}", std::process::id()));
+	let write = |path: &str, code: &str| {
+		let path = root.join(path);
+
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(path, code).unwrap();
+	};
+
+	write("src/api/mod.rs", "pub mod repos;\n#[cfg(feature = \"x\")] pub mod param;");
+	write(
+		"src/api/repos/mod.rs",
+		"#[cfg(test)] mod test;\n\
+		 mod generated;\n\
+		 #[api_impl::api]\n#[properties(category = \"repos\")]\npub struct A<'a> {}\n\
+		 #[derive(Debug)]\n#[api_impl::api]\npub struct B;\n\
+		 #[api_impl::param]\npub enum C {}",
+	);
+	write("src/api/repos/generated.rs", "#[api_impl::api]\npub struct D<'a> {}");
+	write("src/api/param.rs", "pub mod inner {\n#[api_impl::api]\npub struct E<'a> {}\n}");
+
+	let entries = registry::collect(&root).unwrap();
+
+	fs::remove_dir_all(&root).unwrap();
+
+	assert_eq!(
+		entries.iter().map(|e| (e.module.join("::"), e.name.as_str())).collect::<Vec<_>>(),
+		[
+			("repos".into(), "D"),
+			("repos".into(), "A"),
+			("repos".into(), "B"),
+			("param::inner".into(), "E")
+		]
+	);
+	assert_eq!(
+		registry::generate(&entries),
+		r#"//! Generated by `cargo xtask codegen` from the `#[api_impl::api]` structs.
+//!
+//! Don't edit it by hand, it's regenerated along with the APIs.
+
+// hack-ink
+use super::*;
+use crate::api::{param::inner::*, repos::*};
+
+register_endpoints! {
+	D<'a>,
+	A<'a>,
+	B,
+	#[cfg(feature = "x")]
+	E<'a>,
+}
+"#
+	);
+}
+
+#[test]
+fn generate_should_work() {
+	let spec = Spec::from_value(serde_json::json!({
+		"paths": {
+			"/repos/{owner}/{repo}/issues/{issue_number}/lock": {
+				"put": {
+					"summary": "Lock an issue",
+					"operationId": "issues/lock",
+					"externalDocs": { "url": "https://docs.github.com/rest/issues/issues#lock-an-issue" },
+					"deprecated": true,
+					"parameters": [
+						{ "$ref": "#/components/parameters/owner" },
+						{ "name": "repo", "in": "path", "required": true, "schema": { "type": "string" } },
+						{
+							"name": "issue_number",
+							"in": "path",
+							"required": true,
+							"schema": { "type": "integer" }
+						},
+						{ "name": "If-Match", "in": "header", "schema": { "type": "string" } },
+						{
+							"name": "since",
+							"in": "query",
+							"schema": { "type": "string", "format": "date-time" }
+						}
+					],
+					"requestBody": {
+						"content": {
+							"application/json": {
+								"schema": {
+									"type": "object",
+									"properties": {
+										"lock_reason": {
+											"type": "string",
+											"enum": ["off-topic", "too heated", "resolved", "spam"]
+										},
+										"direction": { "type": "string", "enum": ["desc", "asc"] },
+										"labels": { "type": "array", "items": { "type": "string" } },
+										"self": { "type": "string" }
+									},
+									"required": ["lock_reason"]
+								}
+							}
+						}
+					},
+					"responses": {
+						"200": {
+							"content": {
+								"application/json": {
+									"schema": { "$ref": "#/components/schemas/reaction-rollup" }
+								}
+							}
+						}
+					},
+					"x-github": { "category": "issues" }
+				}
+			}
+		},
+		"components": {
+			"parameters": {
+				"owner": { "name": "owner", "in": "path", "required": true, "schema": { "type": "string" } }
+			},
+			"schemas": {
+				"reaction-rollup": {
+					"type": "object",
+					"properties": {
+						"+1": { "type": "integer" },
+						"id": { "type": "integer" },
+						"created_at": { "type": "string", "format": "date-time" },
+						"type": { "type": "string", "nullable": true },
+						"user": {
+							"type": "object",
+							"properties": { "login": { "type": "string" } },
+							"required": ["login"]
+						},
+						"anything": { "oneOf": [{ "type": "string" }, { "type": "integer" }] },
+						"Self": { "type": "string" }
+					},
+					"required": ["+1", "type"]
+				}
+			}
+		}
+	}));
+	let code = generate(&spec, &["issues/lock"]).unwrap().remove("issues").unwrap();
+
+	assert_eq!(
+		code,
+		r#"//! Generated by `cargo xtask codegen` from GitHub's OpenAPI description.
+//!
+//! Don't edit it by hand, allowlist the operation in `xtask/spec/allowlist`.
+
+// hack-ink
+use crate::prelude::*;
+
+#[api_impl::api]
+#[properties(
+	category = "issues",
+	method = "PUT",
+	accept = "application/vnd.github+json",
+	uri = "/repos/{}/{}/issues/{}/lock",
+	deprecated = "deprecated by GitHub"
+)]
+pub struct LockAnIssue<'a> {
+	#[path_param]
+	pub owner: &'a str,
+	#[path_param]
+	pub repo: &'a str,
+	#[path_param]
+	pub issue_number: u32,
+	#[header_param("If-Match")]
+	pub if_match: Option<&'a str>,
+	#[into]
+	pub since: Option<Timestamp>,
+	#[payload_ess_param]
+	pub lock_reason: LockAnIssueLockReason,
+	pub direction: Option<Direction>,
+	#[into]
+	pub labels: Option<List<'a>>,
+	#[rename = "self"]
+	pub self_: Option<&'a str>,
+}
+
+/// `lock_reason` of [`LockAnIssue`].
+#[api_impl::param]
+pub enum LockAnIssueLockReason {
+	#[rename = "off-topic"]
+	OffTopic,
+	#[rename = "too heated"]
+	TooHeated,
+	Resolved,
+	Spam,
+}
+
+/// GitHub's `reaction-rollup` schema.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReactionRollup {
+	#[allow(missing_docs)]
+	#[cfg_attr(feature = "serde", serde(rename = "+1"))]
+	pub plus_one: i64,
+	#[allow(missing_docs)]
+	pub id: Option<u64>,
+	#[allow(missing_docs)]
+	pub created_at: Option<Timestamp>,
+	#[allow(missing_docs)]
+	pub r#type: Option<String>,
+	#[allow(missing_docs)]
+	pub user: Option<ReactionRollupUser>,
+	// Skipped `anything`, unsupported schema.
+	#[allow(missing_docs)]
+	#[cfg_attr(feature = "serde", serde(rename = "Self"))]
+	pub self_: Option<String>,
+}
+
+/// `user` of [`ReactionRollup`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReactionRollupUser {
+	#[allow(missing_docs)]
+	pub login: String,
+}
+"#
+	);
+}
+
+#[test]
+fn generate_should_reject_unsupported_parameters() {
+	let spec = Spec::from_value(serde_json::json!({
+		"paths": {
+			"/repos/{owner}/{repo}/hooks": {
+				"post": {
+					"summary": "Create a repository webhook",
+					"operationId": "repos/create-webhook",
+					"requestBody": {
+						"content": {
+							"application/json": {
+								"schema": {
+									"type": "object",
+									"properties": { "config": { "type": "object" } }
+								}
+							}
+						}
+					},
+					"x-github": { "category": "repos" }
+				}
+			}
+		}
+	}));
+
+	assert_eq!(
+		generate(&spec, &["repos/create-webhook"]).unwrap_err().to_string(),
+		"parameter `config` of `CreateARepositoryWebhook` has an unsupported schema, implement the \
+		 API by hand"
+	);
+}
+
+/// Every registered API must be reachable through the handles of `Client`.
+#[test]
+fn handles_should_reach_every_endpoint() {
+	use std::collections::{BTreeMap, BTreeSet};
+
+	fn ident(ty: &syn::Type) -> Option<&syn::PathSegment> {
+		match ty {
+			syn::Type::Path(ty) => ty.path.segments.last(),
+			_ => None,
+		}
+	}
+
+	let root = workspace_root();
+	// Handle to the handles and the APIs which its methods return.
+	let mut methods = BTreeMap::<String, (Vec<String>, Vec<String>)>::new();
+
+	for path in ["src/client/mod.rs", "src/client/handle.rs"] {
+		let file = syn::parse_file(&fs::read_to_string(root.join(path)).unwrap()).unwrap();
+
+		for item in file.items {
+			let syn::Item::Impl(item) = item else { continue };
+			let Some(handle) = ident(&item.self_ty) else { continue };
+			let (handles, apis) = methods.entry(handle.ident.to_string()).or_default();
+
+			for item in item.items {
+				let syn::ImplItem::Fn(item) = item else { continue };
+				let syn::Visibility::Public(_) = item.vis else { continue };
+				let syn::ReturnType::Type(_, ty) = item.sig.output else { continue };
+				let Some(output) = ident(&ty) else { continue };
+
+				if output.ident != "Request" {
+					handles.push(output.ident.to_string());
+
+					continue;
+				}
+
+				let syn::PathArguments::AngleBracketed(args) = &output.arguments else { continue };
+
+				for arg in &args.args {
+					if let syn::GenericArgument::Type(ty) = arg {
+						apis.extend(ident(ty).map(|api| api.ident.to_string()));
+					}
+				}
+			}
+		}
+	}
+
+	let mut reachable = BTreeSet::new();
+	let mut visited = BTreeSet::new();
+	let mut handles = vec![String::from("Client")];
+
+	while let Some(handle) = handles.pop() {
+		if !visited.insert(handle.clone()) {
+			continue;
+		}
+		if let Some((children, apis)) = methods.get(&handle) {
+			handles.extend(children.iter().cloned());
+			reachable.extend(apis.iter().cloned());
+		}
+	}
+
+	for entry in registry::collect(&root).unwrap() {
+		assert!(
+			reachable.contains(&entry.name),
+			"`{}` isn't reachable through the handles of `Client`, add a method to `src/client/handle.rs`",
+			entry.name
+		);
+	}
+}
@@ -0,0 +1,350 @@
+//! Compare the crate's endpoints with the OpenAPI description in `xtask/spec`.
+//!
+//! The report is printed as JSON. Drifts which are not recorded in `xtask/spec/drift.json` are
+//! regressions and fail the task, run `cargo xtask drift --bless` to accept the current state.
+//! Only the operations which aren't implemented yet could be accepted, the other drifts are gaps
+//! of the implemented endpoints and must be fixed.
+
+#[cfg(test)] mod test;
+
+// std
+use std::{collections::BTreeSet, env, fs, path::Path};
+// crates.io
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+// githuber
+use crate::{
+	codegen::{docs, pascal, uri},
+	openapi::*,
+	workspace_root,
+};
+use githuber::prelude::*;
+
+/// Result of the comparison.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+	/// Pinned ref of the vendored description, `None` for the hand-maintained excerpt.
+	///
+	/// The excerpt only holds the implemented operations, so the missing endpoints can't be told.
+	pub version: Option<String>,
+	/// Number of the operations in the description.
+	pub operations: usize,
+	/// Number of the operations implemented by the crate.
+	pub implemented: usize,
+	/// Sorted differences.
+	pub drifts: Vec<Drift>,
+}
+impl Report {
+	/// Drifts which are not recorded in the baseline.
+	pub fn regressions<'a>(&'a self, baseline: &Report) -> Vec<&'a Drift> {
+		let baseline = baseline.drifts.iter().collect::<BTreeSet<_>>();
+
+		self.drifts.iter().filter(|drift| !baseline.contains(drift)).collect()
+	}
+
+	/// Drifts of the implemented endpoints, which can't be accepted into the baseline.
+	pub fn gaps(&self) -> Vec<&Drift> {
+		self.drifts.iter().filter(|drift| drift.kind != DriftKind::MissingEndpoint).collect()
+	}
+}
+
+/// A difference between an endpoint and its operation.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Drift {
+	/// Name of the request struct, empty if the crate doesn't implement the operation.
+	pub endpoint: String,
+	/// Operation ID, empty if the description doesn't contain the endpoint.
+	pub operation: String,
+	/// What's different.
+	pub kind: DriftKind,
+	/// Human readable detail.
+	pub detail: String,
+}
+
+/// Kind of a [`Drift`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DriftKind {
+	/// The operation isn't implemented.
+	MissingEndpoint,
+	/// The endpoint isn't described.
+	UnknownEndpoint,
+	/// The endpoint's name doesn't match the operation's summary.
+	Name,
+	/// The HTTP method doesn't match.
+	Method,
+	/// The URI template doesn't match.
+	Uri,
+	/// The server doesn't match.
+	BaseUri,
+	/// The kind of the request body doesn't match.
+	Body,
+	/// The kind of the response body doesn't match.
+	Response,
+	/// The documentation URL doesn't match.
+	Docs,
+	/// The deprecation doesn't match.
+	Deprecated,
+	/// A parameter of the operation isn't implemented.
+	MissingParam,
+	/// A parameter of the endpoint isn't described.
+	UnknownParam,
+	/// A parameter's required-ness doesn't match.
+	Required,
+}
+
+const DEFAULT_BASE_URI: &str = "https://api.github.com";
+
+pub fn run() -> Result<()> {
+	let root = workspace_root();
+	let baseline_path = root.join("xtask/spec/drift.json");
+	let report = check_all(&root)?;
+	let json = serde_json::to_string_pretty(&report)? + "\n";
+
+	if env::args().any(|arg| arg == "--bless") {
+		let gaps = report.gaps();
+
+		if !gaps.is_empty() {
+			print_drifts(&gaps);
+
+			bail!("found {} gap(s) of the implemented endpoints, fix them instead", gaps.len());
+		}
+
+		fs::write(&baseline_path, json)?;
+
+		eprintln!("Blessed {}", baseline_path.display());
+
+		return Ok(());
+	}
+
+	println!("{json}");
+
+	if report.version.is_none() {
+		eprintln!(
+			"The description is a hand-maintained excerpt, vendor it with `cargo xtask vendor \
+			 <REF>` to find the missing endpoints"
+		);
+	}
+
+	let baseline = serde_json::from_str::<Report>(&fs::read_to_string(&baseline_path)?)?;
+
+	if report.version != baseline.version {
+		bail!(
+			"the baseline is blessed against {}, run `cargo xtask drift --bless` to catch up with {}",
+			describe(&baseline.version),
+			describe(&report.version)
+		);
+	}
+
+	let regressions = report.regressions(&baseline);
+
+	if !regressions.is_empty() {
+		print_drifts(&regressions);
+
+		bail!("found {} regression(s) against {}", regressions.len(), baseline_path.display());
+	}
+	if report != baseline {
+		eprintln!("Some drifts are fixed, run `cargo xtask drift --bless` to update the baseline");
+	}
+
+	Ok(())
+}
+
+fn describe(version: &Option<String>) -> String {
+	match version {
+		Some(version) => format!("description {version}"),
+		None => "the excerpt".into(),
+	}
+}
+
+fn print_drifts(drifts: &[&Drift]) {
+	for drift in drifts {
+		eprintln!("{} ({}): {:?}, {}", drift.endpoint, drift.operation, drift.kind, drift.detail);
+	}
+}
+
+/// Compare [`githuber::api::endpoints`] with the description in `xtask/spec`.
+pub fn check_all(root: &Path) -> Result<Report> {
+	let mut report = check(
+		&Spec::load(&root.join("xtask/spec/api.github.com.json"))?,
+		githuber::api::endpoints(),
+	);
+
+	report.version = fs::read_to_string(root.join("xtask/spec/version"))
+		.ok()
+		.map(|version| version.trim().into());
+
+	Ok(report)
+}
+
+/// Compare the endpoints with the description, whose version is left to the caller.
+pub fn check(spec: &Spec, endpoints: &[EndpointMetadata]) -> Report {
+	let operations = spec.operations();
+	let mut matched = BTreeSet::new();
+	let mut drifts = Vec::new();
+
+	for endpoint in endpoints {
+		let method = format!("{:?}", endpoint.method).to_uppercase();
+		let found = operations
+			.iter()
+			.position(|operation| pascal(operation.summary) == endpoint.name)
+			.or_else(|| {
+				operations.iter().position(|operation| {
+					operation.method == method && uri(operation.path) == uri(endpoint.uri)
+				})
+			});
+		let Some(i) = found else {
+			drifts.push(Drift {
+				endpoint: endpoint.name.into(),
+				operation: String::new(),
+				kind: DriftKind::UnknownEndpoint,
+				detail: format!("{method} {}", endpoint.uri),
+			});
+
+			continue;
+		};
+		let operation = &operations[i];
+		let mut drift = |kind, detail| {
+			drifts.push(Drift {
+				endpoint: endpoint.name.into(),
+				operation: operation.id.into(),
+				kind,
+				detail,
+			})
+		};
+
+		matched.insert(i);
+
+		if pascal(operation.summary) != endpoint.name {
+			drift(DriftKind::Name, format!("expected `{}`", pascal(operation.summary)));
+		}
+		if operation.method != method {
+			drift(DriftKind::Method, format!("expected `{}`, found `{method}`", operation.method));
+		}
+		if uri(operation.path) != uri(endpoint.uri) {
+			drift(
+				DriftKind::Uri,
+				format!("expected `{}`, found `{}`", operation.path, endpoint.uri),
+			);
+		}
+		if operation.base_uri.unwrap_or(DEFAULT_BASE_URI) != endpoint.base_uri {
+			drift(
+				DriftKind::BaseUri,
+				format!(
+					"expected `{}`, found `{}`",
+					operation.base_uri.unwrap_or(DEFAULT_BASE_URI),
+					endpoint.base_uri
+				),
+			);
+		}
+		if operation.binary_body != endpoint.binary_body {
+			drift(
+				DriftKind::Body,
+				format!(
+					"expected a {} body",
+					if operation.binary_body { "binary" } else { "JSON" }
+				),
+			);
+		}
+		if (operation.binary_response, operation.boolean_response)
+			!= (endpoint.binary_response, endpoint.boolean_response)
+		{
+			drift(
+				DriftKind::Response,
+				format!(
+					"expected a {} response",
+					match (operation.binary_response, operation.boolean_response) {
+						(true, _) => "binary",
+						(_, true) => "boolean",
+						_ => "JSON",
+					}
+				),
+			);
+		}
+		if !operation.docs.is_empty() && docs(operation.docs) != endpoint.docs {
+			drift(
+				DriftKind::Docs,
+				format!("expected `{}`, found `{}`", docs(operation.docs), endpoint.docs),
+			);
+		}
+		if operation.deprecated != endpoint.deprecated.is_some() {
+			drift(
+				DriftKind::Deprecated,
+				format!("should {}be deprecated", if operation.deprecated { "" } else { "not " }),
+			);
+		}
+
+		let expected = params(operation);
+		let found = endpoint
+			.required_params
+			.iter()
+			.map(|param| (param.name.to_owned(), true))
+			.chain(endpoint.optional_params.iter().map(|param| (param.name.to_owned(), false)))
+			.chain(
+				endpoint
+					.header_params
+					.iter()
+					// Media types aren't described as parameters.
+					.filter(|param| !param.name.eq_ignore_ascii_case("accept"))
+					.map(|param| (param.name.to_lowercase(), !param.ty.starts_with("Option<"))),
+			)
+			.collect::<Vec<_>>();
+
+		for (name, required) in &expected {
+			match found.iter().find(|(n, _)| n == name) {
+				None => drift(DriftKind::MissingParam, format!("`{name}`")),
+				Some((_, r)) if r != required => drift(
+					DriftKind::Required,
+					format!(
+						"`{name}` should be {}",
+						if *required { "required" } else { "optional" }
+					),
+				),
+				_ => (),
+			}
+		}
+		for (name, _) in &found {
+			if !expected.iter().any(|(n, _)| n == name) {
+				drift(DriftKind::UnknownParam, format!("`{name}`"));
+			}
+		}
+	}
+	for (i, operation) in operations.iter().enumerate() {
+		if !matched.contains(&i) {
+			drifts.push(Drift {
+				endpoint: String::new(),
+				operation: operation.id.into(),
+				kind: DriftKind::MissingEndpoint,
+				detail: format!("{} {}", operation.method, operation.path),
+			});
+		}
+	}
+
+	drifts.sort();
+
+	Report { version: None, operations: operations.len(), implemented: matched.len(), drifts }
+}
+
+/// Query, header and body parameters of the operation, with their required-ness.
+///
+/// Header names are lowercased, since they are case insensitive.
+fn params(operation: &Operation) -> Vec<(String, bool)> {
+	let body = operation.body.unwrap_or(&Value::Null);
+	let required = body["required"].as_array().cloned().unwrap_or_default();
+
+	operation
+		.parameters
+		.iter()
+		.filter(|param| param.location != "path")
+		.map(|param| match param.location {
+			"header" => (param.name.to_lowercase(), param.required),
+			_ => (param.name.to_owned(), param.required),
+		})
+		.chain(
+			body["properties"].as_object().into_iter().flatten().map(|(name, _)| {
+				(name.to_owned(), required.iter().any(|r| r.as_str() == Some(name)))
+			}),
+		)
+		.collect()
+}
@@ -0,0 +1,180 @@
+use super::*;
+
+#[test]
+fn drift_should_not_regress() {
+	let root = workspace_root();
+	let report = check_all(&root).unwrap();
+	let baseline = serde_json::from_str::<Report>(
+		&fs::read_to_string(root.join("xtask/spec/drift.json")).unwrap(),
+	)
+	.unwrap();
+
+	assert_eq!(report.version, baseline.version, "run `cargo xtask drift --bless` after vendoring");
+	assert_eq!(baseline.gaps(), Vec::<&Drift>::new(), "gaps can't be accepted into the baseline");
+	assert_eq!(report.regressions(&baseline), Vec::<&Drift>::new(), "see `cargo xtask drift`");
+	assert_eq!(report, baseline, "drifts are fixed, run `cargo xtask drift --bless`");
+}
+
+#[test]
+fn check_should_work() {
+	const fn param(name: &'static str, ty: &'static str) -> ParamMetadata {
+		ParamMetadata { name, ty }
+	}
+	const fn endpoint(
+		name: &'static str,
+		method: Method,
+		uri: &'static str,
+		required_params: &'static [ParamMetadata],
+		optional_params: &'static [ParamMetadata],
+	) -> EndpointMetadata {
+		EndpointMetadata {
+			name,
+			category: "commits",
+			method,
+			uri,
+			accept: "application/vnd.github+json",
+			path_params: &[],
+			required_params,
+			optional_params,
+			header_params: &[],
+			docs: "",
+			deprecated: None,
+			preview: None,
+			base_uri: "https://api.github.com",
+			binary_body: false,
+			binary_response: false,
+			boolean_response: false,
+		}
+	}
+
+	let spec = Spec::from_value(serde_json::json!({
+		"paths": {
+			"/repos/{owner}/{repo}/commits/{ref}": {
+				"get": {
+					"summary": "Get a commit",
+					"operationId": "repos/get-commit",
+					"externalDocs": { "url": "https://docs.github.com/rest/commits/commits#get-a-commit" },
+					"deprecated": true,
+					"parameters": [
+						{ "name": "owner", "in": "path", "required": true },
+						{ "name": "repo", "in": "path", "required": true },
+						{ "name": "ref", "in": "path", "required": true }
+					]
+				}
+			},
+			"/repos/{owner}/{repo}/comments": {
+				"post": {
+					"summary": "Create a commit comment",
+					"operationId": "repos/create-commit-comment",
+					"parameters": [
+						{ "name": "owner", "in": "path", "required": true },
+						{ "name": "repo", "in": "path", "required": true }
+					],
+					"requestBody": {
+						"content": {
+							"application/json": {
+								"schema": {
+									"type": "object",
+									"properties": {
+										"body": { "type": "string" },
+										"path": { "type": "string" }
+									},
+									"required": ["body"]
+								}
+							}
+						}
+					}
+				}
+			},
+			"/repos/{owner}/{repo}/commits/{ref}/status": {
+				"get": {
+					"summary": "Get the combined status for a specific reference",
+					"operationId": "repos/get-combined-status-for-ref",
+					"parameters": []
+				}
+			}
+		}
+	}));
+	const ENDPOINTS: &[EndpointMetadata] = &[
+		endpoint(
+			"GetACommit",
+			Method::Get,
+			"/repos/{owner}/{repo}/commits/{ref}",
+			&[],
+			&[param("per_page", "u8"), param("page", "u16")],
+		),
+		endpoint(
+			"CreateACommitComment",
+			Method::Patch,
+			"/repos/{owner}/{repo}/commit-comments",
+			&[param("path", "&'a str")],
+			&[],
+		),
+		endpoint("ListCommitComments", Method::Get, "/repos/{owner}/{repo}/comments", &[], &[]),
+	];
+
+	let report = check(&spec, ENDPOINTS);
+	let drift = |endpoint: &str, operation: &str, kind, detail: &str| Drift {
+		endpoint: endpoint.into(),
+		operation: operation.into(),
+		kind,
+		detail: detail.into(),
+	};
+
+	assert_eq!(
+		report,
+		Report {
+			version: None,
+			operations: 3,
+			implemented: 2,
+			drifts: vec![
+				drift(
+					"",
+					"repos/get-combined-status-for-ref",
+					DriftKind::MissingEndpoint,
+					"GET /repos/{owner}/{repo}/commits/{ref}/status"
+				),
+				drift(
+					"CreateACommitComment",
+					"repos/create-commit-comment",
+					DriftKind::Method,
+					"expected `POST`, found `PATCH`"
+				),
+				drift(
+					"CreateACommitComment",
+					"repos/create-commit-comment",
+					DriftKind::Uri,
+					"expected `/repos/{owner}/{repo}/comments`, found \
+					 `/repos/{owner}/{repo}/commit-comments`"
+				),
+				drift(
+					"CreateACommitComment",
+					"repos/create-commit-comment",
+					DriftKind::MissingParam,
+					"`body`"
+				),
+				drift(
+					"CreateACommitComment",
+					"repos/create-commit-comment",
+					DriftKind::Required,
+					"`path` should be optional"
+				),
+				drift(
+					"GetACommit",
+					"repos/get-commit",
+					DriftKind::Docs,
+					"expected `https://docs.github.com/en/rest/commits/commits#get-a-commit`, found ``"
+				),
+				drift("GetACommit", "repos/get-commit", DriftKind::Deprecated, "should be deprecated"),
+				drift("GetACommit", "repos/get-commit", DriftKind::UnknownParam, "`page`"),
+				drift("GetACommit", "repos/get-commit", DriftKind::UnknownParam, "`per_page`"),
+				drift(
+					"ListCommitComments",
+					"",
+					DriftKind::UnknownEndpoint,
+					"GET /repos/{owner}/{repo}/comments"
+				),
+			]
+		}
+	);
+}
@@ -0,0 +1,43 @@
+//! GitHuber development tasks.
+//!
+//! Run `cargo xtask <TASK>` from anywhere inside the workspace.
+
+mod codegen;
+mod drift;
+mod openapi;
+mod vendor;
+
+// std
+use std::{
+	env,
+	path::{Path, PathBuf},
+};
+// crates.io
+use anyhow::Result;
+
+fn main() -> Result<()> {
+	match env::args().nth(1).as_deref() {
+		Some("codegen") => codegen::run(),
+		Some("drift") => drift::run(),
+		Some("vendor") => vendor::run(),
+		_ => {
+			eprintln!("Usage: cargo xtask <TASK>");
+			eprintln!();
+			eprintln!("Tasks:");
+			eprintln!(
+				"  codegen  Generate the allowlisted APIs from `xtask/spec/api.github.com.json`"
+			);
+			eprintln!(
+				"  drift    Compare the endpoints with `xtask/spec/api.github.com.json`, `--bless` to \
+				 update the baseline"
+			);
+			eprintln!("  vendor   Vendor GitHub's OpenAPI description of the given ref");
+
+			Ok(())
+		},
+	}
+}
+
+fn workspace_root() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
@@ -0,0 +1,142 @@
+//! Minimal reader of GitHub's OpenAPI description.
+
+// std
+use std::{fs, path::Path};
+// crates.io
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+/// HTTP methods in the order of GitHub's documentation.
+const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+/// GitHub's OpenAPI description.
+pub struct Spec(Value);
+impl Spec {
+	/// Load the description from a JSON file.
+	pub fn load(path: &Path) -> Result<Self> {
+		let spec = fs::read_to_string(path)
+			.with_context(|| format!("failed to read {}", path.display()))?;
+
+		Ok(Self(serde_json::from_str(&spec)?))
+	}
+
+	/// Wrap an already parsed description.
+	#[cfg(test)]
+	pub fn from_value(spec: Value) -> Self {
+		Self(spec)
+	}
+
+	/// Follow the `$ref`s until a concrete value.
+	pub fn resolve<'a>(&'a self, value: &'a Value) -> &'a Value {
+		match value.get("$ref").and_then(Value::as_str) {
+			Some(r#ref) =>
+				self.resolve(self.0.pointer(r#ref.trim_start_matches('#')).unwrap_or(&Value::Null)),
+			None => value,
+		}
+	}
+
+	/// All the operations, in order of the description.
+	pub fn operations(&self) -> Vec<Operation<'_>> {
+		let Some(paths) = self.0["paths"].as_object() else { return Vec::new() };
+
+		paths
+			.iter()
+			.flat_map(|(path, item)| {
+				METHODS.into_iter().filter_map(move |method| {
+					let operation = item.get(method)?;
+					let parameters = item["parameters"]
+						.as_array()
+						.into_iter()
+						.chain(operation["parameters"].as_array())
+						.flatten()
+						.map(|parameter| Parameter::new(self.resolve(parameter), self))
+						.collect();
+
+					Some(Operation {
+						id: operation["operationId"].as_str().unwrap_or_default(),
+						method: method.to_uppercase(),
+						path,
+						summary: operation["summary"].as_str().unwrap_or_default(),
+						docs: operation["externalDocs"]["url"].as_str().unwrap_or_default(),
+						deprecated: operation["deprecated"].as_bool().unwrap_or_default(),
+						base_uri: operation["servers"][0]["url"].as_str(),
+						binary_body: operation["requestBody"]["content"]
+							.get("application/octet-stream")
+							.is_some(),
+						binary_response: operation["responses"].get("302").is_some()
+							|| operation["responses"]["200"]["content"]
+								.get("application/octet-stream")
+								.is_some(),
+						boolean_response: method == "get"
+							&& operation["responses"].get("204").is_some()
+							&& operation["responses"].get("404").is_some(),
+						category: operation["x-github"]["category"].as_str().unwrap_or_default(),
+						parameters,
+						body: operation["requestBody"]["content"]["application/json"]
+							.get("schema")
+							.map(|schema| self.resolve(schema)),
+						response: ["200", "201"].into_iter().find_map(|status| {
+							operation["responses"][status]["content"]["application/json"]
+								.get("schema")
+						}),
+					})
+				})
+			})
+			.collect()
+	}
+}
+
+/// An operation, aka an API.
+pub struct Operation<'a> {
+	pub id: &'a str,
+	pub method: String,
+	pub path: &'a str,
+	pub summary: &'a str,
+	/// GitHub documentation URL.
+	pub docs: &'a str,
+	pub deprecated: bool,
+	/// Server of the operation, if it isn't `https://api.github.com`.
+	pub base_uri: Option<&'a str>,
+	/// Whether the request body is `application/octet-stream`.
+	pub binary_body: bool,
+	/// Whether the response is a redirection to or an `application/octet-stream` binary.
+	pub binary_response: bool,
+	/// Whether the operation is a check, which responds `204` or `404` without a body.
+	pub boolean_response: bool,
+	pub category: &'a str,
+	pub parameters: Vec<Parameter<'a>>,
+	/// Resolved schema of the JSON request body.
+	pub body: Option<&'a Value>,
+	/// Unresolved schema of the JSON response, to keep the component's name.
+	pub response: Option<&'a Value>,
+}
+impl Operation<'_> {
+	/// Names of the path parameters, in order of the URI template.
+	pub fn path_params(&self) -> Vec<&str> {
+		self.path
+			.split('{')
+			.skip(1)
+			.filter_map(|segment| segment.split_once('}').map(|(name, _)| name))
+			.collect()
+	}
+}
+
+/// A path, query or header parameter.
+pub struct Parameter<'a> {
+	pub name: &'a str,
+	pub location: &'a str,
+	pub required: bool,
+	pub schema: &'a Value,
+}
+impl<'a> Parameter<'a> {
+	fn new(parameter: &'a Value, spec: &'a Spec) -> Self {
+		let schema = spec.resolve(&parameter["schema"]);
+
+		Self {
+			name: parameter["name"].as_str().unwrap_or_default(),
+			location: parameter["in"].as_str().unwrap_or_default(),
+			required: parameter["required"].as_bool().unwrap_or_default(),
+			schema,
+		}
+	}
+}
@@ -0,0 +1,43 @@
+//! Vendor GitHub's OpenAPI description.
+//!
+//! The description is downloaded unedited from a ref of `github/rest-api-description`, which is
+//! pinned in `xtask/spec/version`.
+
+// std
+use std::{env, fs, process::Command};
+// crates.io
+use anyhow::{bail, Context, Result};
+// githuber
+use crate::workspace_root;
+
+const UPSTREAM: &str = "https://raw.githubusercontent.com/github/rest-api-description";
+
+pub fn run() -> Result<()> {
+	let Some(r#ref) = env::args().nth(2) else {
+		bail!("expect a ref of `github/rest-api-description`, e.g. `v2.1.0`");
+	};
+	let url = format!("{UPSTREAM}/{ref}/descriptions/api.github.com/api.github.com.json");
+	let output = Command::new("curl")
+		.args(["--fail", "--location", "--silent", "--show-error"])
+		.arg(&url)
+		.output()
+		.context("failed to run `curl`")?;
+
+	if !output.status.success() {
+		bail!("failed to download {url}: {}", String::from_utf8_lossy(&output.stderr).trim());
+	}
+
+	// Make sure it's a description before replacing the vendored one.
+	serde_json::from_slice::<serde_json::Value>(&output.stdout)
+		.with_context(|| format!("{url} isn't a JSON document"))?;
+
+	let spec = workspace_root().join("xtask/spec");
+
+	fs::write(spec.join("api.github.com.json"), &output.stdout)?;
+	fs::write(spec.join("version"), format!("{ref}\n"))?;
+
+	println!("Vendored GitHub's OpenAPI description {ref}");
+	println!("Run `cargo xtask codegen` and `cargo xtask drift` to catch up with it");
+
+	Ok(())
+}